@@ -0,0 +1,53 @@
+//! Compares `ErrorOption::map` against `ErrorOption::map_copied` on `Copy` payloads.
+//!
+//! Run with `cargo bench --features bench`.
+
+use std::{hint::black_box, time::{Duration, Instant}};
+
+use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+
+const LEN: usize = 1 << 16;
+const ROUNDS: u32 = 200;
+
+fn input() -> Vec<ErrorOption<u64, u32>> {
+    (0..LEN as u64)
+        .map(|i| match i % 3 {
+            0 => Value(i),
+            1 => Empty,
+            _ => Error(i as u32)
+        })
+        .collect()
+}
+
+fn bench<F>(name: &str, mut f: F)
+where
+    F: FnMut() -> u64
+{
+    // Warm up the caches before measuring.
+    black_box(f());
+
+    let mut total = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        black_box(f());
+        total += start.elapsed();
+    }
+
+    println!("{name:<12} {:>10.1?} per iteration", total / ROUNDS);
+}
+
+fn main() {
+    let items = input();
+
+    bench("map", || {
+        items.iter()
+            .map(|item| black_box(*item).map(|x| x.wrapping_mul(3)).unwrap_or(0))
+            .fold(0, u64::wrapping_add)
+    });
+
+    bench("map_copied", || {
+        items.iter()
+            .map(|item| black_box(item).map_copied(|x| x.wrapping_mul(3)).unwrap_or(0))
+            .fold(0, u64::wrapping_add)
+    });
+}
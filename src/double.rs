@@ -1,7 +1,21 @@
-#[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Display, Formatter},
+    ops::ControlFlow,
+    pin::Pin
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box
+};
+
+#[cfg(feature = "alloc")]
+use core::any::Any;
 
-use core::pin::Pin;
+#[cfg(feature = "std")]
+use std::error::Error;
 
 use self::Double::{This, That};
 
@@ -15,6 +29,39 @@ pub enum Double<T, U> {
 }
 
 impl<T, U> Double<T, U> {
+    /////////////////////////////////////////////////////////////////////////
+    // Constructors
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Creates a `This`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double;
+    /// const FOO: Double<i32, &str> = Double::new_this(42);
+    /// assert_eq!(FOO, Double::This(42));
+    /// ```
+    #[inline]
+    pub const fn new_this(this: T) -> Self {
+        This(this)
+    }
+
+    /// Creates a `That`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double;
+    /// const FOO: Double<i32, &str> = Double::new_that("that");
+    /// const _: () = assert!(matches!(FOO, Double::That(_)));
+    /// assert_eq!(FOO, Double::That("that"));
+    /// ```
+    #[inline]
+    pub const fn new_that(that: U) -> Self {
+        That(that)
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Querying the contained values
     /////////////////////////////////////////////////////////////////////////
@@ -56,6 +103,26 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Returns the name of the variant, without formatting the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.variant_name(), "This");
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.variant_name(), "That");
+    /// ```
+    #[inline]
+    pub const fn variant_name(&self) -> &'static str {
+        match *self {
+            This(_) => "This",
+            That(_) => "That"
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Adapter for each variant
     /////////////////////////////////////////////////////////////////////////
@@ -76,24 +143,266 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Returns the contained `This` value, or hands back the untouched `Double` as [`Err`].
+    ///
+    /// Unlike [`this`], the `That` payload is not dropped on failure.
+    ///
+    /// [`this`]: Double::this
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use urt::double::Double::{self, This, That};
+    /// struct Tracked(Rc<Cell<u32>>);
+    ///
+    /// impl Drop for Tracked {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Rc::new(Cell::new(0));
+    ///
+    /// let foo: Double<i32, Tracked> = This(42);
+    /// assert_eq!(foo.try_this().ok(), Some(42));
+    ///
+    /// let bar: Double<i32, Tracked> = That(Tracked(drops.clone()));
+    /// let bar = bar.try_this().unwrap_err();
+    /// assert_eq!(drops.get(), 0);
+    /// assert!(bar.is_that());
+    ///
+    /// drop(bar);
+    /// assert_eq!(drops.get(), 1);
+    /// ```
+    #[doc(alias = "try_into_this")]
+    #[inline]
+    pub fn try_this(self) -> Result<T, Self> {
+        match self {
+            This(this) => Ok(this),
+            that => Err(that)
+        }
+    }
+
+    /// Returns the contained `That` value, or hands back the untouched `Double` as [`Err`].
+    ///
+    /// Unlike [`that`], the `This` payload is not dropped on failure.
+    ///
+    /// [`that`]: Double::that
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use urt::double::Double::{self, This, That};
+    /// struct Tracked(Rc<Cell<u32>>);
+    ///
+    /// impl Drop for Tracked {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Rc::new(Cell::new(0));
+    ///
+    /// let foo: Double<Tracked, &str> = That("that");
+    /// assert_eq!(foo.try_that().ok(), Some("that"));
+    ///
+    /// let bar: Double<Tracked, &str> = This(Tracked(drops.clone()));
+    /// let bar = bar.try_that().unwrap_err();
+    /// assert_eq!(drops.get(), 0);
+    /// assert!(bar.is_this());
+    ///
+    /// drop(bar);
+    /// assert_eq!(drops.get(), 1);
+    /// ```
+    #[doc(alias = "try_into_that")]
+    #[inline]
+    pub fn try_that(self) -> Result<U, Self> {
+        match self {
+            That(that) => Ok(that),
+            this => Err(this)
+        }
+    }
+
+    /// Converts the `Double` into a pair of [`Option`]s, exactly one of which is [`Some`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_options(), (Some(42), None));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_options(), (None, Some("that")));
+    /// ```
+    #[inline]
+    pub fn into_options(self) -> (Option<T>, Option<U>) {
+        match self {
+            This(this) => (Some(this), None),
+            That(that) => (None, Some(that))
+        }
+    }
+
+    /// Converts from `&Double<T, U>` to a pair of [`Option`]s of references, exactly one of which is [`Some`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.as_options(), (Some(&42), None));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.as_options(), (None, Some(&"that")));
+    /// ```
+    #[inline]
+    pub fn as_options(&self) -> (Option<&T>, Option<&U>) {
+        match *self {
+            This(ref this) => (Some(this), None),
+            That(ref that) => (None, Some(that))
+        }
+    }
+
+    /// Maps `Double` to [`ControlFlow`], `This` continues and `That` breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use urt::double::Double::{self, This, That};
+    /// let visit = |sum: u32, x: u32| -> Double<u32, u32> {
+    ///     if x > 3 { That(x) } else { This(sum + x) }
+    /// };
+    ///
+    /// let foo = [1, 2, 3].into_iter().try_fold(0, |sum, x| visit(sum, x).into_control_flow());
+    /// assert_eq!(foo, ControlFlow::Continue(6));
+    ///
+    /// let bar = [1, 5, 3].into_iter().try_fold(0, |sum, x| visit(sum, x).into_control_flow());
+    /// assert_eq!(bar, ControlFlow::Break(5));
+    /// ```
+    #[inline]
+    pub fn into_control_flow(self) -> ControlFlow<U, T> {
+        match self {
+            This(this) => ControlFlow::Continue(this),
+            That(that) => ControlFlow::Break(that)
+        }
+    }
+
+    /// Maps `Double` to [`ControlFlow`], `That` continues and `This` breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_control_flow_that_continues(), ControlFlow::Break(42));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_control_flow_that_continues(), ControlFlow::Continue("that"));
+    /// ```
+    #[inline]
+    pub fn into_control_flow_that_continues(self) -> ControlFlow<T, U> {
+        match self {
+            This(this) => ControlFlow::Break(this),
+            That(that) => ControlFlow::Continue(that)
+        }
+    }
+
     #[must_use = "directly use a result without casting from `Double`"]
+    #[deprecated(since = "0.1.0", note = "use `into_untagged` instead")]
     #[inline]
     pub fn this_as_result<E>(self) -> Result<T, U> {
+        self.into_untagged()
+    }
+
+    #[must_use = "directly use a result without casting from `Double`"]
+    #[deprecated(since = "0.1.0", note = "use `into_untagged_flipped` instead")]
+    #[inline]
+    pub fn that_as_result<E>(self) -> Result<U, T> {
+        self.into_untagged_flipped()
+    }
+
+    /// Maps `Double` to [`Result`], `This` becomes `Ok` and `That` becomes `Err`.
+    ///
+    /// Both arms are carried over unchanged, so no information is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_untagged(), Ok(42));
+    /// assert_eq!(foo.into_untagged().map_or_else(That, This), foo);
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_untagged(), Err("that"));
+    /// assert_eq!(bar.into_untagged().map_or_else(That, This), bar);
+    /// ```
+    #[must_use = "directly use a result without casting from `Double`"]
+    #[inline]
+    pub fn into_untagged(self) -> Result<T, U> {
         match self {
             This(this) => Ok(this),
             That(that) => Err(that)
         }
     }
 
+    /// Maps `Double` to [`Result`], `That` becomes `Ok` and `This` becomes `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_untagged_flipped(), Err(42));
+    /// assert_eq!(foo.into_untagged_flipped().map_or_else(This, That), foo);
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_untagged_flipped(), Ok("that"));
+    /// assert_eq!(bar.into_untagged_flipped().map_or_else(This, That), bar);
+    /// ```
     #[must_use = "directly use a result without casting from `Double`"]
     #[inline]
-    pub fn that_as_result<E>(self) -> Result<U, T> {
+    pub fn into_untagged_flipped(self) -> Result<U, T> {
         match self {
             This(this) => Err(this),
             That(that) => Ok(that)
         }
     }
 
+    /// Maps `Double` to [`Result`], applying `f` to a `This` value for `Ok`
+    /// and `g` to a `That` value for `Err`.
+    ///
+    /// Use [`flip`](Double::flip) first to make `That` the `Ok` arm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.try_into_result(|x| x * 2, str::len), Ok(84));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.try_into_result(|x| x * 2, str::len), Err(4));
+    ///
+    /// let baz: Double<i32, &str> = That("that");
+    /// assert_eq!(baz.flip().try_into_result(str::len, |x| x * 2), Ok(4));
+    /// ```
+    #[inline]
+    pub fn try_into_result<O, E, F, G>(self, f: F, g: G) -> Result<O, E>
+    where
+        F: FnOnce(T) -> O,
+        G: FnOnce(U) -> E
+    {
+        match self {
+            This(this) => Ok(f(this)),
+            That(that) => Err(g(that))
+        }
+    }
+
     #[inline]
     pub fn this_or<E>(self, err: E) -> Result<T, E> {
         match self {
@@ -142,6 +451,25 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Converts from `&Double<T, U>` to `Double<&U, &T>`, flipping without moving.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.flipped_ref(), That(&42));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.flipped_ref(), This(&"that"));
+    /// ```
+    #[inline]
+    pub fn flipped_ref(&self) -> Double<&U, &T> {
+        match *self {
+            This(ref this) => That(this),
+            That(ref that) => This(that)
+        }
+    }
 
     #[inline]
     pub fn switch(self) -> Double<U, T> {
@@ -170,6 +498,79 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Borrows the contained value as `&B`, regardless of the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<String, &str> = This(String::from("owned"));
+    /// assert_eq!(foo.as_common_ref::<str>(), "owned");
+    ///
+    /// let bar: Double<String, &str> = That("borrowed");
+    /// assert_eq!(bar.as_common_ref::<str>(), "borrowed");
+    ///
+    /// let baz: Double<Vec<u8>, &[u8]> = That(&[1, 2, 3]);
+    /// assert_eq!(baz.as_common_ref::<[u8]>(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_common_ref<B: ?Sized>(&self) -> &B
+    where
+        T: Borrow<B>,
+        U: Borrow<B>
+    {
+        match *self {
+            This(ref this) => this.borrow(),
+            That(ref that) => that.borrow()
+        }
+    }
+
+    /// Converts the contained value to `&B` via [`AsRef`], regardless of the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<String, &str> = This(String::from("owned"));
+    /// assert_eq!(foo.as_common::<[u8]>(), b"owned");
+    ///
+    /// let bar: Double<Vec<u8>, &[u8]> = That(&[1, 2, 3]);
+    /// assert_eq!(bar.as_common::<[u8]>(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_common<B: ?Sized>(&self) -> &B
+    where
+        T: AsRef<B>,
+        U: AsRef<B>
+    {
+        match *self {
+            This(ref this) => this.as_ref(),
+            That(ref that) => that.as_ref()
+        }
+    }
+
+    /// Applies `f` to the contained value borrowed as `&B`, regardless of the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<String, &str> = This(String::from("owned"));
+    /// assert_eq!(foo.map_common(str::len), 5);
+    ///
+    /// let bar: Double<Vec<u8>, &[u8]> = That(&[1, 2, 3]);
+    /// assert_eq!(bar.map_common(|bytes: &[u8]| bytes.iter().sum::<u8>()), 6);
+    /// ```
+    #[inline]
+    pub fn map_common<B: ?Sized, R, F>(&self, f: F) -> R
+    where
+        T: Borrow<B>,
+        U: Borrow<B>,
+        F: FnOnce(&B) -> R
+    {
+        f(self.as_common_ref())
+    }
+
     #[inline]
     pub fn as_pin_ref(self: Pin<&Self>) -> Double<Pin<&T>, Pin<&U>> {
         unsafe {
@@ -212,6 +613,100 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Returns the contained `This` value, building the panic message lazily.
+    ///
+    /// The message is only built if `self` is `That`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `That`, with the built message and the `That` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.expect_this_with(|| -> String { unreachable!() }), 42);
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// let panic = std::panic::catch_unwind(|| bar.expect_this_with(|| format!("request {}", 7))).unwrap_err();
+    /// # // `panic_immediate_abort` panics without a message.
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "request 7: \"that\"");
+    /// # }
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::double::Double::{self, That};
+    /// let foo: Double<i32, &str> = That("that");
+    /// foo.expect_this_with(|| format!("request {}", 7)); // panics with `request 7: "that"`
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_this_with<F, D>(self, f: F) -> T
+    where
+        F: FnOnce() -> D,
+        D: Display,
+        U: Debug
+    {
+        #[cfg(feature = "panic_immediate_abort")]
+        let _ = f;
+        match self {
+            This(this) => this,
+            #[cfg(not(feature = "panic_immediate_abort"))]
+            That(that) => expect_failed(&f(), &that),
+            #[cfg(feature = "panic_immediate_abort")]
+            That(_) => panic!()
+        }
+    }
+
+    /// Returns the contained `That` value, building the panic message lazily.
+    ///
+    /// The message is only built if `self` is `This`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `This`, with the built message and the `This` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = That("that");
+    /// assert_eq!(foo.expect_that_with(|| -> String { unreachable!() }), "that");
+    ///
+    /// let bar: Double<i32, &str> = This(42);
+    /// let panic = std::panic::catch_unwind(|| bar.expect_that_with(|| format!("request {}", 7))).unwrap_err();
+    /// # // `panic_immediate_abort` panics without a message.
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "request 7: 42");
+    /// # }
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::double::Double::{self, This};
+    /// let foo: Double<i32, &str> = This(42);
+    /// foo.expect_that_with(|| format!("request {}", 7)); // panics with `request 7: 42`
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_that_with<F, D>(self, f: F) -> U
+    where
+        F: FnOnce() -> D,
+        D: Display,
+        T: Debug
+    {
+        #[cfg(feature = "panic_immediate_abort")]
+        let _ = f;
+        match self {
+            That(that) => that,
+            #[cfg(not(feature = "panic_immediate_abort"))]
+            This(this) => expect_failed(&f(), &this),
+            #[cfg(feature = "panic_immediate_abort")]
+            This(_) => panic!()
+        }
+    }
+
     #[inline]
     #[track_caller]
     pub fn unwrap_this(self) -> T {
@@ -337,6 +832,85 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Expands the `Double` into a `(T, U)` pair, filling the missing side with the passed default.
+    ///
+    /// Only one of the defaults is ever used, the other one is dropped. Use
+    /// [`into_tuple_with_else`] to avoid constructing the unused default.
+    ///
+    /// [`into_tuple_with_else`]: Double::into_tuple_with_else
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::rc::Rc;
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_tuple_with(0, "-"), (42, "-"));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_tuple_with(0, "-"), (0, "that"));
+    ///
+    /// let unused = Rc::new(());
+    /// let baz: Double<i32, Rc<()>> = That(Rc::new(()));
+    /// let (this, _) = baz.into_tuple_with(0, unused.clone());
+    /// assert_eq!(this, 0);
+    /// assert_eq!(Rc::strong_count(&unused), 1);
+    /// ```
+    #[inline]
+    pub fn into_tuple_with(self, default_this: T, default_that: U) -> (T, U) {
+        match self {
+            This(this) => (this, default_that),
+            That(that) => (default_this, that)
+        }
+    }
+
+    /// Expands the `Double` into a `(T, U)` pair, computing the missing side with the matching closure.
+    ///
+    /// Only the closure for the missing side is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_tuple_with_else(|| unreachable!(), || "-"), (42, "-"));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_tuple_with_else(|| 0, || unreachable!()), (0, "that"));
+    /// ```
+    #[inline]
+    pub fn into_tuple_with_else<F, G>(self, f: F, g: G) -> (T, U)
+    where
+        F: FnOnce() -> T,
+        G: FnOnce() -> U
+    {
+        match self {
+            This(this) => (this, g()),
+            That(that) => (f(), that)
+        }
+    }
+
+    /// Expands the `Double` into a `(T, U)` pair, filling the missing side with its default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_tuple_default(), (42, ""));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_tuple_default(), (0, "that"));
+    /// ```
+    #[inline]
+    pub fn into_tuple_default(self) -> (T, U)
+    where
+        T: Default,
+        U: Default
+    {
+        self.into_tuple_with_else(T::default, U::default)
+    }
+
     #[inline]
     pub fn unwrap_union<F, O, V, R>(self, other: Self, f: F) -> O
     where 
@@ -350,6 +924,42 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Combines `self` and `other` if they hold opposite variants, passing the
+    /// `This` value first and the `That` value second to `f`.
+    ///
+    /// Unlike [`unwrap_union`](Double::unwrap_union) this returns `default` instead of
+    /// panicking if both hold the same variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let concat = |x: i32, s: &str| format!("{s}{x}");
+    ///
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.union_or(That("that"), concat, String::new()), "that42");
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.union_or(This(42), concat, String::new()), "that42");
+    ///
+    /// let baz: Double<i32, &str> = This(42);
+    /// assert_eq!(baz.union_or(This(7), concat, String::new()), "");
+    ///
+    /// let qux: Double<i32, &str> = That("that");
+    /// assert_eq!(qux.union_or(That("other"), concat, String::new()), "");
+    /// ```
+    #[inline]
+    pub fn union_or<O, F>(self, other: Self, f: F, default: O) -> O
+    where
+        F: FnOnce(T, U) -> O
+    {
+        match (self, other) {
+            (This(a), That(b)) => f(a, b),
+            (That(a), This(b)) => f(b, a),
+            _ => default
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Transforming contained values
     /////////////////////////////////////////////////////////////////////////
@@ -388,6 +998,400 @@ impl<T, U> Double<T, U> {
         }
     }
 
+    /// Works like [`map`](Double::map), but borrows `self` and passes references to the closures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<String, Vec<i32>> = This("this".to_string());
+    /// assert_eq!(foo.bimap_ref(String::len, Vec::is_empty), This(4));
+    ///
+    /// let bar: Double<String, Vec<i32>> = That(vec![1, 2]);
+    /// assert_eq!(bar.bimap_ref(String::len, Vec::is_empty), That(false));
+    ///
+    /// // Both are still usable.
+    /// assert_eq!(foo, This("this".to_string()));
+    /// assert_eq!(bar, That(vec![1, 2]));
+    /// ```
+    #[inline]
+    pub fn bimap_ref<F, G, O, R>(&self, f: F, g: G) -> Double<O, R>
+    where
+        F: FnOnce(&T) -> O,
+        G: FnOnce(&U) -> R
+    {
+        match *self {
+            This(ref this) => This(f(this)),
+            That(ref that) => That(g(that))
+        }
+    }
+
+    /// Converts both variants via [`Into`], keeping the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<u8, &str> = This(42);
+    /// assert_eq!(foo.into_double::<u64, String>(), This(42));
+    ///
+    /// let bar: Double<u8, &str> = That("that");
+    /// assert_eq!(bar.into_double::<u64, String>(), That(String::from("that")));
+    /// ```
+    #[inline]
+    pub fn into_double<O, R>(self) -> Double<O, R>
+    where
+        T: Into<O>,
+        U: Into<R>
+    {
+        match self {
+            This(this) => This(this.into()),
+            That(that) => That(that.into())
+        }
+    }
+
+    /// Converts `This` via [`Into`], leaving `That` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<u8, &str> = This(42);
+    /// assert_eq!(foo.this_into::<u64>(), This(42));
+    ///
+    /// let bar: Double<u8, &str> = That("that");
+    /// assert_eq!(bar.this_into::<u64>(), That("that"));
+    /// ```
+    #[inline]
+    pub fn this_into<O>(self) -> Double<O, U>
+    where
+        T: Into<O>
+    {
+        match self {
+            This(this) => This(this.into()),
+            That(that) => That(that)
+        }
+    }
+
+    /// Converts `That` via [`Into`], leaving `This` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<u8, &str> = This(42);
+    /// assert_eq!(foo.that_into::<String>(), This(42));
+    ///
+    /// let bar: Double<u8, &str> = That("that");
+    /// assert_eq!(bar.that_into::<String>(), That(String::from("that")));
+    /// ```
+    #[inline]
+    pub fn that_into<R>(self) -> Double<T, R>
+    where
+        U: Into<R>
+    {
+        match self {
+            This(this) => This(this),
+            That(that) => That(that.into())
+        }
+    }
+
+    /// Folds a mixed stream of `Double`s, applying `f` to every `This` and `g` to every `That`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let stream: Vec<Double<u32, &str>> = vec![This(1), That("ab"), This(2), That("cde")];
+    /// let total = Double::fold(stream, 0, |sum, n| sum + n as usize, |sum, s| sum + s.len());
+    /// assert_eq!(total, 8);
+    /// ```
+    #[inline]
+    pub fn fold<I, A, F, G>(iter: I, init: A, mut f: F, mut g: G) -> A
+    where
+        I: IntoIterator<Item = Self>,
+        F: FnMut(A, T) -> A,
+        G: FnMut(A, U) -> A
+    {
+        iter.into_iter().fold(init, |accumulator, double| match double {
+            This(this) => f(accumulator, this),
+            That(that) => g(accumulator, that)
+        })
+    }
+}
+
+impl<A, B, C, D> Double<(A, B), (C, D)> {
+    /// Splits a `Double` of tuples into two `Double`s sharing the original variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<(usize, char), (usize, &str)> = This((3, 'a'));
+    /// assert_eq!(foo.unzip(), (This(3), This('a')));
+    ///
+    /// let bar: Double<(usize, char), (usize, &str)> = That((7, "b"));
+    /// assert_eq!(bar.unzip(), (That(7), That("b")));
+    /// ```
+    #[inline]
+    pub fn unzip(self) -> (Double<A, C>, Double<B, D>) {
+        match self {
+            This((a, b)) => (This(a), This(b)),
+            That((c, d)) => (That(c), That(d))
+        }
+    }
+
+    /// Splits a `&Double` of tuples into two `Double`s of references sharing the original variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<(usize, char), (usize, &str)> = This((3, 'a'));
+    /// assert_eq!(foo.unzip_ref(), (This(&3), This(&'a')));
+    ///
+    /// let bar: Double<(usize, char), (usize, &str)> = That((7, "b"));
+    /// assert_eq!(bar.unzip_ref(), (That(&7), That(&"b")));
+    /// ```
+    #[inline]
+    pub fn unzip_ref(&self) -> (Double<&A, &C>, Double<&B, &D>) {
+        match *self {
+            This((ref a, ref b)) => (This(a), This(b)),
+            That((ref c, ref d)) => (That(c), That(d))
+        }
+    }
+
+    /// Inverse of [`unzip`], returns [`None`] if the two `Double`s disagree on the variant.
+    ///
+    /// [`unzip`]: Double::unzip
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<(usize, char), (usize, &str)> = This((3, 'a'));
+    /// assert_eq!(Double::zip_same_variant(foo.unzip()), Some(foo));
+    ///
+    /// let bar: Double<(usize, char), (usize, &str)> = That((7, "b"));
+    /// assert_eq!(Double::zip_same_variant(bar.unzip()), Some(bar));
+    ///
+    /// let mixed: (Double<usize, usize>, Double<char, &str>) = (This(3), That("b"));
+    /// assert_eq!(Double::zip_same_variant(mixed), None);
+    /// ```
+    #[inline]
+    pub fn zip_same_variant(pair: (Double<A, C>, Double<B, D>)) -> Option<Self> {
+        match pair {
+            (This(a), This(b)) => Some(This((a, b))),
+            (That(c), That(d)) => Some(That((c, d))),
+            _ => None
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> Double<T, U> {
+    /// Erases both variants into a boxed [`Error`] trait object.
+    ///
+    /// The same pattern works for any trait both sides implement, e.g. for [`Display`]
+    /// use `double.unwrap_to(|t| Box::new(t) as Box<dyn Display>, |u| Box::new(u) as Box<dyn Display>)`.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<std::fmt::Error, std::io::Error> = This(std::fmt::Error);
+    /// assert!(foo.into_boxed_error().is::<std::fmt::Error>());
+    ///
+    /// let bar: Double<std::fmt::Error, std::io::Error> = That(std::io::ErrorKind::NotFound.into());
+    /// assert!(bar.into_boxed_error().is::<std::io::Error>());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn into_boxed_error(self) -> Box<dyn Error + Send + Sync>
+    where
+        T: Error + Send + Sync + 'static,
+        U: Error + Send + Sync + 'static
+    {
+        match self {
+            This(this) => Box::new(this),
+            That(that) => Box::new(that)
+        }
+    }
+
+    /// Erases both variants into a boxed [`Any`] trait object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<i32, &str> = This(42);
+    /// assert_eq!(foo.into_boxed_any().downcast::<i32>().ok(), Some(Box::new(42)));
+    ///
+    /// let bar: Double<i32, &str> = That("that");
+    /// assert_eq!(bar.into_boxed_any().downcast::<&str>().ok(), Some(Box::new("that")));
+    /// ```
+    #[inline]
+    pub fn into_boxed_any(self) -> Box<dyn Any>
+    where
+        T: Any,
+        U: Any
+    {
+        match self {
+            This(this) => Box::new(this),
+            That(that) => Box::new(that)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'b, A: ToOwned + ?Sized, B: ToOwned + ?Sized> Double<&'a A, &'b B> {
+    /// Maps a `Double<&A, &B>` to a `Double<A::Owned, B::Owned>` via [`ToOwned`].
+    ///
+    /// Unlike cloning the references this also works for unsized data like `str` or `[T]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<&str, &[u8]> = This("this");
+    /// assert_eq!(foo.owned(), This::<String, Vec<u8>>(String::from("this")));
+    ///
+    /// let bar: Double<&str, &[u8]> = That(&[1, 2, 3]);
+    /// assert_eq!(bar.owned(), That::<String, Vec<u8>>(vec![1, 2, 3]));
+    /// ```
+    #[inline]
+    pub fn owned(self) -> Double<A::Owned, B::Owned> {
+        match self {
+            This(this) => This(this.to_owned()),
+            That(that) => That(that.to_owned())
+        }
+    }
+
+    /// Maps a `Double<&A, &B>` to a `Double<A::Owned, &B>` via [`ToOwned`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<&str, &[u8]> = This("this");
+    /// assert_eq!(foo.this_owned(), This::<String, &[u8]>(String::from("this")));
+    ///
+    /// let bar: Double<&str, &[u8]> = That(&[1, 2, 3]);
+    /// assert_eq!(bar.this_owned(), That::<String, &[u8]>(&[1, 2, 3]));
+    /// ```
+    #[inline]
+    pub fn this_owned(self) -> Double<A::Owned, &'b B> {
+        match self {
+            This(this) => This(this.to_owned()),
+            That(that) => That(that)
+        }
+    }
+
+    /// Maps a `Double<&A, &B>` to a `Double<&A, B::Owned>` via [`ToOwned`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<&str, &[u8]> = This("this");
+    /// assert_eq!(foo.that_owned(), This::<&str, Vec<u8>>("this"));
+    ///
+    /// let bar: Double<&str, &[u8]> = That(&[1, 2, 3]);
+    /// assert_eq!(bar.that_owned(), That::<&str, Vec<u8>>(vec![1, 2, 3]));
+    /// ```
+    #[inline]
+    pub fn that_owned(self) -> Double<&'a A, B::Owned> {
+        match self {
+            This(this) => This(this),
+            That(that) => That(that.to_owned())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ToOwned + ?Sized> Double<&B, B::Owned> {
+    /// Extracts the owned data, cloning a borrowed `This`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<&str, String> = This("borrowed");
+    /// assert_eq!(foo.into_owned(), String::from("borrowed"));
+    ///
+    /// let bar: Double<&str, String> = That(String::from("owned"));
+    /// assert_eq!(bar.into_owned(), String::from("owned"));
+    /// ```
+    #[inline]
+    pub fn into_owned(self) -> B::Owned {
+        match self {
+            This(this) => this.to_owned(),
+            That(that) => that
+        }
+    }
+
+    /// Borrows the contained data regardless of the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::double::Double::{self, This, That};
+    /// let foo: Double<&str, String> = This("borrowed");
+    /// assert_eq!(foo.as_borrowed(), "borrowed");
+    ///
+    /// let bar: Double<&str, String> = That(String::from("owned"));
+    /// assert_eq!(bar.as_borrowed(), "owned");
+    /// ```
+    #[inline]
+    pub fn as_borrowed(&self) -> &B {
+        match *self {
+            This(this) => this,
+            That(ref that) => that.borrow()
+        }
+    }
+}
+
+/// Converts a [`Cow`] into a `Double`, borrowed data becomes `This` and owned data `That`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use urt::double::Double::{self, This, That};
+/// fn shout(text: Cow<'_, str>) -> String {
+///     text.to_uppercase()
+/// }
+///
+/// let foo: Double<&str, String> = Cow::Borrowed("borrowed").into();
+/// assert_eq!(foo, This("borrowed"));
+/// assert_eq!(shout(foo.into()), "BORROWED");
+///
+/// let bar: Double<&str, String> = Cow::<str>::Owned(String::from("owned")).into();
+/// assert_eq!(bar, That(String::from("owned")));
+/// assert_eq!(shout(bar.into()), "OWNED");
+/// ```
+#[cfg(feature = "alloc")]
+impl<'a, B: ToOwned + ?Sized> From<Cow<'a, B>> for Double<&'a B, B::Owned> {
+    #[inline]
+    fn from(cow: Cow<'a, B>) -> Self {
+        match cow {
+            Cow::Borrowed(borrowed) => This(borrowed),
+            Cow::Owned(owned) => That(owned)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, B: ToOwned + ?Sized> From<Double<&'a B, B::Owned>> for Cow<'a, B> {
+    #[inline]
+    fn from(double: Double<&'a B, B::Owned>) -> Self {
+        match double {
+            This(this) => Cow::Borrowed(this),
+            That(that) => Cow::Owned(that)
+        }
+    }
 }
 
 impl<T: Clone, U: Clone> Clone for Double<T, U> {
@@ -405,4 +1409,191 @@ impl<T: Clone, U: Clone> Clone for Double<T, U> {
             (destination, source) => *destination = source.clone()
         }        
     }
-}
\ No newline at end of file
+}
+
+/// Compares a `Double` with a [`Result`], treating `This` as [`Ok`] and `That` as [`Err`].
+///
+/// # Examples
+///
+/// ```
+/// # use urt::double::Double::{self, This, That};
+/// let foo: Double<i32, &str> = This(42);
+/// assert!(foo == Ok(42));
+/// assert!(foo != Ok(7));
+/// assert!(foo != Err("that"));
+///
+/// let bar: Double<i32, &str> = That("that");
+/// assert!(bar == Err("that"));
+/// assert!(bar != Err("other"));
+/// assert!(bar != Ok(42));
+/// ```
+impl<T: PartialEq, U: PartialEq> PartialEq<Result<T, U>> for Double<T, U> {
+    #[inline]
+    fn eq(&self, other: &Result<T, U>) -> bool {
+        match (self, other) {
+            (This(this), Ok(ok)) => this == ok,
+            (That(that), Err(err)) => that == err,
+            _ => false
+        }
+    }
+}
+
+// This is a separate function to reduce the code size of the expect_*_with methods.
+#[cfg(not(feature = "panic_immediate_abort"))]
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn expect_failed(msg: &dyn Display, value: &dyn Debug) -> ! {
+    panic!("{msg}: {value:?}")
+}
+
+/// Converts a [`ControlFlow`] into a `Double`, `Continue` becomes `This` and `Break` becomes `That`.
+///
+/// # Examples
+///
+/// ```
+/// # use core::ops::ControlFlow;
+/// # use urt::double::Double::{self, This, That};
+/// let foo: Double<i32, &str> = ControlFlow::Continue(42).into();
+/// assert_eq!(foo, This(42));
+///
+/// let bar: Double<i32, &str> = ControlFlow::Break("that").into();
+/// assert_eq!(bar, That("that"));
+/// ```
+impl<T, U> From<ControlFlow<U, T>> for Double<T, U> {
+    #[inline]
+    fn from(flow: ControlFlow<U, T>) -> Self {
+        match flow {
+            ControlFlow::Continue(this) => This(this),
+            ControlFlow::Break(that) => That(that)
+        }
+    }
+}
+
+/// Error returned when converting a pair of [`Option`]s which is not exactly one [`Some`] into a `Double`.
+///
+/// The payloads are handed back in the `Both` case so no data is lost.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FromOptionsError<T, U> {
+    /// Both options were [`None`].
+    Neither,
+    /// Both options were [`Some`].
+    Both(T, U)
+}
+
+impl<T, U> Display for FromOptionsError<T, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FromOptionsError::Neither => f.write_str("expected exactly one `Some`, found two `None`s"),
+            FromOptionsError::Both(_, _) => f.write_str("expected exactly one `Some`, found two `Some`s")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug, U: Debug> Error for FromOptionsError<T, U> {}
+
+/// Converts a pair of [`Option`]s into a `Double`, failing unless exactly one is [`Some`].
+///
+/// # Examples
+///
+/// ```
+/// # use urt::double::{FromOptionsError, Double::{self, This, That}};
+/// let foo: Double<i32, &str> = This(42);
+/// assert_eq!(Double::try_from(foo.into_options()), Ok(foo));
+///
+/// let bar: Double<i32, &str> = That("that");
+/// assert_eq!(Double::try_from(bar.into_options()), Ok(bar));
+///
+/// let neither: (Option<i32>, Option<&str>) = (None, None);
+/// assert_eq!(Double::try_from(neither), Err(FromOptionsError::Neither));
+///
+/// let both: (Option<i32>, Option<&str>) = (Some(42), Some("that"));
+/// assert_eq!(Double::try_from(both), Err(FromOptionsError::Both(42, "that")));
+/// ```
+impl<T, U> TryFrom<(Option<T>, Option<U>)> for Double<T, U> {
+    type Error = FromOptionsError<T, U>;
+
+    #[inline]
+    fn try_from(options: (Option<T>, Option<U>)) -> Result<Self, Self::Error> {
+        match options {
+            (Some(this), None) => Ok(This(this)),
+            (None, Some(that)) => Ok(That(that)),
+            (None, None) => Err(FromOptionsError::Neither),
+            (Some(this), Some(that)) => Err(FromOptionsError::Both(this, that))
+        }
+    }
+}
+
+/// Serde helpers reading and writing `Double` in the wire format of `either::Either`.
+///
+/// The derived implementation of `Double` uses the external tags `This` and `That`.
+/// Data previously serialized from an `either::Either` uses `Left` and `Right` instead,
+/// use this module via `#[serde(with = "urt::double::serde_either_compat")]` to stay compatible,
+/// `This` maps to `Left` and `That` maps to `Right`.
+///
+/// Only the externally tagged form is covered, there is no untagged helper for `Double`.
+/// For an untagged `DoubleOption` see [`doubleoption::untagged`](crate::doubleoption::untagged).
+///
+/// # Examples
+///
+/// ```
+/// # use serde::{Serialize, Deserialize};
+/// # use urt::double::Double::{self, This, That};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Message {
+///     #[serde(with = "urt::double::serde_either_compat")]
+///     payload: Double<u32, String>
+/// }
+///
+/// // Captured from `either::Either<u32, String>`'s derive.
+/// let left = r#"{"payload":{"Left":42}}"#;
+/// let right = r#"{"payload":{"Right":"that"}}"#;
+///
+/// let foo = Message { payload: This(42) };
+/// assert_eq!(serde_json::to_string(&foo).unwrap(), left);
+/// assert_eq!(serde_json::from_str::<Message>(left).unwrap(), foo);
+///
+/// let bar = Message { payload: That(String::from("that")) };
+/// assert_eq!(serde_json::to_string(&bar).unwrap(), right);
+/// assert_eq!(serde_json::from_str::<Message>(right).unwrap(), bar);
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_either_compat {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Double::{self, This, That};
+
+    #[derive(Deserialize)]
+    #[serde(rename = "Either")]
+    enum Either<L, R> {
+        Left(L),
+        Right(R)
+    }
+
+    /// Serializes `This` as `Left` and `That` as `Right`.
+    pub fn serialize<T, U, S>(double: &Double<T, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        U: Serialize,
+        S: Serializer
+    {
+        match double {
+            This(this) => serializer.serialize_newtype_variant("Either", 0, "Left", this),
+            That(that) => serializer.serialize_newtype_variant("Either", 1, "Right", that)
+        }
+    }
+
+    /// Deserializes `Left` as `This` and `Right` as `That`.
+    pub fn deserialize<'de, T, U, D>(deserializer: D) -> Result<Double<T, U>, D::Error>
+    where
+        T: Deserialize<'de>,
+        U: Deserialize<'de>,
+        D: Deserializer<'de>
+    {
+        match Either::deserialize(deserializer)? {
+            Either::Left(left) => Ok(This(left)),
+            Either::Right(right) => Ok(That(right))
+        }
+    }
+}
@@ -1,12 +1,3272 @@
+use core::{fmt::{self, Debug, Display, Formatter}, hint, iter::FusedIterator, mem, pin::Pin};
+#[cfg(feature = "str")]
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{double::Double, erroroption::ErrorOption};
+
 /// Unopinionated extention of Option by another [`Some`] value.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[must_use = "this `DoubleOption` may hold a value, which should be used"]
 pub enum DoubleOption<T, U> {
     Fist(T),
     Second(U),
     Empty
 }
 
+impl<T, U> DoubleOption<T, U> {
+    /////////////////////////////////////////////////////////////////////////
+    // Querying the contained values
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `DoubleOption` is `Fist`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.is_first(), true);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.is_first(), false);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.is_first(), false);
+    /// ```
+    ///
+    /// The predicates can be evaluated in const contexts:
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// const FOO: DoubleOption<i32, &str> = Fist(42);
+    /// const BAR: DoubleOption<i32, &str> = Second("second");
+    /// const BAZ: DoubleOption<i32, &str> = Empty;
+    ///
+    /// const _: () = {
+    ///     assert!(FOO.is_first() && !FOO.is_second() && !FOO.is_empty());
+    ///     assert!(!BAR.is_first() && BAR.is_second() && !BAR.is_empty());
+    ///     assert!(!BAZ.is_first() && !BAZ.is_second() && BAZ.is_empty());
+    /// };
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_first(&self) -> bool {
+        matches!(self, Self::Fist(_))
+    }
+
+    /// Returns `true` if the `DoubleOption` is `Second`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.is_second(), true);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.is_second(), false);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.is_second(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_second(&self) -> bool {
+        matches!(self, Self::Second(_))
+    }
+
+    /// Returns `true` if the `DoubleOption` is `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(foo.is_empty(), true);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.is_empty(), false);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(baz.is_empty(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    /// Returns `true` if the `DoubleOption` is not `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.is_any(), true);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.is_any(), true);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.is_any(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_any(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Returns `true` if the `DoubleOption` is `Fist` or `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.is_first_or_empty(), true);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.is_first_or_empty(), false);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.is_first_or_empty(), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_first_or_empty(&self) -> bool {
+        matches!(self, Self::Fist(_) | Self::Empty)
+    }
+
+    /// Returns `true` if the `DoubleOption` is `Second` or `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.is_second_or_empty(), false);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.is_second_or_empty(), true);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.is_second_or_empty(), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_second_or_empty(&self) -> bool {
+        matches!(self, Self::Second(_) | Self::Empty)
+    }
+
+    /// Returns the name of the variant, without formatting the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.variant_name(), "Fist");
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.variant_name(), "Second");
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.variant_name(), "Empty");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn variant_name(&self) -> &'static str {
+        match *self {
+            Self::Fist(_) => "Fist",
+            Self::Second(_) => "Second",
+            Self::Empty => "Empty"
+        }
+    }
+
+    /////////////////////////////////////////////////////////////////////////
+    // Adapter for each variant
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Maps `DoubleOption` to [`Option`], where `Second` and `Empty` map to [`None`].
+    ///
+    /// A `Second` value is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.first(), Some(42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.first(), None);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.first(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn first(self) -> Option<T> {
+        match self {
+            Self::Fist(first) => Some(first),
+            _ => None
+        }
+    }
+
+    /// Maps `DoubleOption` to [`Option`], where `Fist` and `Empty` map to [`None`].
+    ///
+    /// A `Fist` value is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.second(), Some("second"));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.second(), None);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.second(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn second(self) -> Option<U> {
+        match self {
+            Self::Second(second) => Some(second),
+            _ => None
+        }
+    }
+
+    /// Maps `DoubleOption` to a pair of [`Option`]s, at most one of which is [`Some`].
+    ///
+    /// Despite its name this consumes `self`, unlike [`Double::as_options`]. Use
+    /// [`into_options`], or `as_ref().into_options()` to borrow the payload.
+    ///
+    /// [`Double::as_options`]: crate::double::Double::as_options
+    /// [`into_options`]: DoubleOption::into_options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.as_options(), (Some(42), None));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.as_options(), (None, Some("second")));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.as_options(), (None, None));
+    /// ```
+    #[must_use]
+    #[deprecated(since = "0.1.0", note = "use `into_options` instead")]
+    #[inline]
+    pub fn as_options(self) -> (Option<T>, Option<U>) {
+        self.into_options()
+    }
+
+    /// Maps `DoubleOption` to a pair of [`Option`]s, at most one of which is [`Some`].
+    ///
+    /// This is the inverse of [`try_from_options`](DoubleOption::try_from_options).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.into_options(), (Some(42), None));
+    /// assert_eq!(DoubleOption::try_from_options(foo.into_options()), Ok(foo));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.into_options(), (None, Some("second")));
+    /// assert_eq!(DoubleOption::try_from_options(bar.into_options()), Ok(bar));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.into_options(), (None, None));
+    /// assert_eq!(DoubleOption::try_from_options(baz.into_options()), Ok(baz));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_options(self) -> (Option<T>, Option<U>) {
+        match self {
+            Self::Fist(first) => (Some(first), None),
+            Self::Second(second) => (None, Some(second)),
+            Self::Empty => (None, None)
+        }
+    }
+
+    /// Creates a `DoubleOption` from a pair of [`Option`]s, keeping the first if both are [`Some`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// assert_eq!(DoubleOption::from_options_first_wins((Some(42), Some("second"))), Fist(42));
+    /// assert_eq!(DoubleOption::from_options_first_wins((None::<i32>, Some("second"))), Second("second"));
+    /// assert_eq!(DoubleOption::from_options_first_wins((None::<i32>, None::<&str>)), Empty);
+    /// ```
+    #[inline]
+    pub fn from_options_first_wins(options: (Option<T>, Option<U>)) -> Self {
+        match options {
+            (Some(first), _) => Self::Fist(first),
+            (None, Some(second)) => Self::Second(second),
+            (None, None) => Self::Empty
+        }
+    }
+
+    /// Creates a `DoubleOption` from a pair of [`Option`]s, keeping the second if both are [`Some`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// assert_eq!(DoubleOption::from_options_second_wins((Some(42), Some("second"))), Second("second"));
+    /// assert_eq!(DoubleOption::from_options_second_wins((Some(42), None::<&str>)), Fist(42));
+    /// assert_eq!(DoubleOption::from_options_second_wins((None::<i32>, None::<&str>)), Empty);
+    /// ```
+    #[inline]
+    pub fn from_options_second_wins(options: (Option<T>, Option<U>)) -> Self {
+        match options {
+            (_, Some(second)) => Self::Second(second),
+            (Some(first), None) => Self::Fist(first),
+            (None, None) => Self::Empty
+        }
+    }
+
+    /// Creates a `DoubleOption` from a pair of [`Option`]s, failing if both are [`Some`].
+    ///
+    /// Both payloads are handed back in the [`BothPresent`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::{BothPresent, DoubleOption::{self, Fist, Second, Empty}};
+    /// assert_eq!(DoubleOption::try_from_options((Some(42), None::<&str>)), Ok(Fist(42)));
+    /// assert_eq!(DoubleOption::try_from_options((None::<i32>, Some("second"))), Ok(Second("second")));
+    /// assert_eq!(DoubleOption::try_from_options((None::<i32>, None::<&str>)), Ok(Empty));
+    ///
+    /// let foo = DoubleOption::try_from_options((Some(42), Some("second")));
+    /// assert_eq!(foo, Err(BothPresent(42, "second")));
+    /// ```
+    #[inline]
+    pub fn try_from_options(options: (Option<T>, Option<U>)) -> Result<Self, BothPresent<T, U>> {
+        match options {
+            (Some(first), None) => Ok(Self::Fist(first)),
+            (None, Some(second)) => Ok(Self::Second(second)),
+            (None, None) => Ok(Self::Empty),
+            (Some(first), Some(second)) => Err(BothPresent(first, second))
+        }
+    }
+
+    /// Maps `DoubleOption` to [`ErrorOption`], treating `Second` as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::erroroption::ErrorOption;
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.into_error_option(), ErrorOption::Value(42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.into_error_option(), ErrorOption::Error("second"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.into_error_option(), ErrorOption::Empty);
+    /// ```
+    #[inline]
+    pub fn into_error_option(self) -> ErrorOption<T, U> {
+        match self {
+            Self::Fist(first) => ErrorOption::Value(first),
+            Self::Second(second) => ErrorOption::Error(second),
+            Self::Empty => ErrorOption::Empty
+        }
+    }
+
+    /// Maps `DoubleOption` to [`ErrorOption`], treating `Fist` as the error.
+    ///
+    /// This is the opposite bias of [`into_error_option`](DoubleOption::into_error_option).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::erroroption::ErrorOption;
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.into_error_option_second(), ErrorOption::Error(42));
+    /// assert_eq!(foo.into_error_option_second().into_double_option(), Second(42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.into_error_option_second(), ErrorOption::Value("second"));
+    /// assert_eq!(bar.into_error_option_second().into_double_option(), Fist("second"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.into_error_option_second(), ErrorOption::Empty);
+    ///
+    /// // `into_error_option` and `ErrorOption::into_double_option` round trip.
+    /// for qux in [foo, bar, baz] {
+    ///     assert_eq!(qux.into_error_option().into_double_option(), qux);
+    /// }
+    ///
+    /// for qux in [ErrorOption::Value(42), ErrorOption::Error("error"), ErrorOption::Empty] {
+    ///     assert_eq!(qux.into_double_option().into_error_option(), qux);
+    /// }
+    /// ```
+    #[inline]
+    pub fn into_error_option_second(self) -> ErrorOption<U, T> {
+        match self {
+            Self::Fist(first) => ErrorOption::Error(first),
+            Self::Second(second) => ErrorOption::Value(second),
+            Self::Empty => ErrorOption::Empty
+        }
+    }
+
+    /// Maps `DoubleOption` to an [`Option`] of [`Double`], where `Empty` maps to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::double::Double::{This, That};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.into_double(), Some(This(42)));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.into_double(), Some(That("second")));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.into_double(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_double(self) -> Option<Double<T, U>> {
+        match self {
+            Self::Fist(first) => Some(Double::This(first)),
+            Self::Second(second) => Some(Double::That(second)),
+            Self::Empty => None
+        }
+    }
+
+    /// Maps `DoubleOption` to the isomorphic `Option<Double<T, U>>`.
+    ///
+    /// This is the same as [`into_double`](DoubleOption::into_double) and the inverse of
+    /// [`from_option_double`](DoubleOption::from_option_double). It gives access to every
+    /// [`Double`] combinator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::double::Double::{This, That};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.into_option_double(), Some(This(42)));
+    /// assert_eq!(DoubleOption::from_option_double(foo.into_option_double()), foo);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.into_option_double(), Some(That("second")));
+    /// assert_eq!(DoubleOption::from_option_double(bar.into_option_double()), bar);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.into_option_double(), None);
+    /// assert_eq!(DoubleOption::from_option_double(baz.into_option_double()), baz);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_option_double(self) -> Option<Double<T, U>> {
+        self.into_double()
+    }
+
+    /// Creates a `DoubleOption` from the isomorphic `Option<Double<T, U>>`, where [`None`] maps to `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::double::Double::{self, This, That};
+    /// assert_eq!(DoubleOption::from_option_double(Some(This::<i32, &str>(42))), Fist(42));
+    /// assert_eq!(DoubleOption::from_option_double(Some(That::<i32, &str>("second"))), Second("second"));
+    /// assert_eq!(DoubleOption::from_option_double(None::<Double<i32, &str>>), Empty);
+    /// ```
+    #[inline]
+    pub fn from_option_double(option: Option<Double<T, U>>) -> Self {
+        match option {
+            Some(Double::This(first)) => Self::Fist(first),
+            Some(Double::That(second)) => Self::Second(second),
+            None => Self::Empty
+        }
+    }
+
+    /// Creates a `DoubleOption` from an [`Option`] of the first type, where [`None`] maps to `Empty`.
+    ///
+    /// The type of the second channel can be named with a turbofish, which a conversion
+    /// through [`Into`] can not infer on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Empty};
+    /// let foo = DoubleOption::<_, &str>::from_first(Some(42));
+    /// assert_eq!(foo, Fist(42));
+    ///
+    /// let bar = DoubleOption::<i32, &str>::from_first(None);
+    /// assert_eq!(bar, Empty);
+    /// ```
+    ///
+    /// Going through [`Double`] and [`Into`] instead leaves the second channel unconstrained:
+    ///
+    /// ```compile_fail
+    /// # use urt::doubleoption::DoubleOption;
+    /// # use urt::double::Double::This;
+    /// let foo: DoubleOption<i32, _> = Some(This(42)).into();
+    /// assert!(foo.is_first());
+    /// ```
+    #[inline]
+    pub fn from_first(first: Option<T>) -> Self {
+        match first {
+            Some(first) => Self::Fist(first),
+            None => Self::Empty
+        }
+    }
+
+    /// Creates a `DoubleOption` from an [`Option`] of the second type, where [`None`] maps to `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Second, Empty};
+    /// let foo = DoubleOption::<i32, _>::from_second(Some("second"));
+    /// assert_eq!(foo, Second("second"));
+    ///
+    /// let bar = DoubleOption::<i32, &str>::from_second(None);
+    /// assert_eq!(bar, Empty);
+    /// ```
+    #[inline]
+    pub fn from_second(second: Option<U>) -> Self {
+        match second {
+            Some(second) => Self::Second(second),
+            None => Self::Empty
+        }
+    }
+
+    /// Creates a `DoubleOption` from a [`Double`], mapping `This` to `Fist` and `That` to `Second`.
+    ///
+    /// The result is never `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second};
+    /// # use urt::double::Double::{This, That};
+    /// assert_eq!(DoubleOption::from_double(This::<i32, &str>(42)), Fist(42));
+    /// assert_eq!(DoubleOption::from_double(That::<i32, &str>("second")), Second("second"));
+    /// ```
+    #[inline]
+    pub fn from_double(double: Double<T, U>) -> Self {
+        match double {
+            Double::This(first) => Self::Fist(first),
+            Double::That(second) => Self::Second(second)
+        }
+    }
+
+    /// Maps `DoubleOption` to a [`Result`] of [`Double`], using `err` as [`Err`] for `Empty`.
+    ///
+    /// Arguments passed to `ok_or` are eagerly evaluated; if you are passing the
+    /// result of a function call, it is recommended to use [`ok_or_else`](DoubleOption::ok_or_else),
+    /// which is lazily evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::double::Double::{This, That};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.ok_or("missing"), Ok(This(42)));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.ok_or("missing"), Ok(That("second")));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.ok_or("missing"), Err("missing"));
+    /// ```
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<Double<T, U>, E> {
+        match self {
+            Self::Fist(first) => Ok(Double::This(first)),
+            Self::Second(second) => Ok(Double::That(second)),
+            Self::Empty => Err(err)
+        }
+    }
+
+    /// Maps `DoubleOption` to a [`Result`] of [`Double`], computing the [`Err`] for `Empty` with `err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// fn lookup(found: DoubleOption<u32, String>) -> Result<String, String> {
+    ///     let found = found.ok_or_else(|| "neither source had an answer".to_string())?;
+    ///     Ok(found.unwrap_to(|id| format!("id {id}"), |name| format!("name {name}")))
+    /// }
+    ///
+    /// assert_eq!(lookup(Fist(42)), Ok("id 42".to_string()));
+    /// assert_eq!(lookup(Second("foo".to_string())), Ok("name foo".to_string()));
+    /// assert_eq!(lookup(Empty), Err("neither source had an answer".to_string()));
+    /// ```
+    #[inline]
+    pub fn ok_or_else<E, F>(self, err: F) -> Result<Double<T, U>, E>
+    where
+        F: FnOnce() -> E
+    {
+        match self {
+            Self::Fist(first) => Ok(Double::This(first)),
+            Self::Second(second) => Ok(Double::That(second)),
+            Self::Empty => Err(err())
+        }
+    }
+
+    /// Maps `DoubleOption` to [`Result`] casting `Fist` to [`Ok`], using `err` as [`Err`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.first_or("no first"), Ok(42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.first_or("no first"), Err("no first"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.first_or("no first"), Err("no first"));
+    /// ```
+    #[inline]
+    pub fn first_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Self::Fist(first) => Ok(first),
+            _ => Err(err)
+        }
+    }
+
+    /// Maps `DoubleOption` to [`Result`] casting `Second` to [`Ok`], using `err` as [`Err`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.second_or("no second"), Err("no second"));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.second_or("no second"), Ok("second"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.second_or("no second"), Err("no second"));
+    /// ```
+    #[inline]
+    pub fn second_or<E>(self, err: E) -> Result<U, E> {
+        match self {
+            Self::Second(second) => Ok(second),
+            _ => Err(err)
+        }
+    }
+
+    /// Maps `DoubleOption` to [`Result`] casting `Fist` to [`Ok`], computing the [`Err`] with `f` otherwise.
+    ///
+    /// `f` receives the discarded `Second` payload, or [`None`] for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let describe = |found: Option<&str>| match found {
+    ///     Some(second) => format!("expected an id, found {second}"),
+    ///     None => "expected an id, found nothing".to_string()
+    /// };
+    ///
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.first_or_else(describe), Ok(42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("a name");
+    /// assert_eq!(bar.first_or_else(describe), Err("expected an id, found a name".to_string()));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.first_or_else(describe), Err("expected an id, found nothing".to_string()));
+    /// ```
+    #[inline]
+    pub fn first_or_else<E, F>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(Option<U>) -> E
+    {
+        match self {
+            Self::Fist(first) => Ok(first),
+            Self::Second(second) => Err(f(Some(second))),
+            Self::Empty => Err(f(None))
+        }
+    }
+
+    /// Maps `DoubleOption` to [`Result`] casting `Second` to [`Ok`], computing the [`Err`] with `f` otherwise.
+    ///
+    /// `f` receives the discarded `Fist` payload, or [`None`] for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let describe = |found: Option<i32>| match found {
+    ///     Some(first) => format!("expected a name, found {first}"),
+    ///     None => "expected a name, found nothing".to_string()
+    /// };
+    ///
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.second_or_else(describe), Err("expected a name, found 42".to_string()));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("a name");
+    /// assert_eq!(bar.second_or_else(describe), Ok("a name"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.second_or_else(describe), Err("expected a name, found nothing".to_string()));
+    /// ```
+    #[inline]
+    pub fn second_or_else<E, F>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(Option<T>) -> E
+    {
+        match self {
+            Self::Fist(first) => Err(f(Some(first))),
+            Self::Second(second) => Ok(second),
+            Self::Empty => Err(f(None))
+        }
+    }
+
+    /// Maps `DoubleOption` to `Result<Option<T>, U>`, treating `Second` as the error.
+    ///
+    /// Unlike [`first_or`](DoubleOption::first_or) absence is not an error, `Empty` maps to `Ok(None)`.
+    /// This is the inverse of [`from_first_result`](DoubleOption::from_first_result).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.first_result(), Ok(Some(42)));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.first_result(), Err("second"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.first_result(), Ok(None));
+    /// ```
+    #[inline]
+    pub fn first_result(self) -> Result<Option<T>, U> {
+        match self {
+            Self::Fist(first) => Ok(Some(first)),
+            Self::Second(second) => Err(second),
+            Self::Empty => Ok(None)
+        }
+    }
+
+    /// Maps `DoubleOption` to `Result<Option<U>, T>`, treating `Fist` as the error.
+    ///
+    /// Unlike [`second_or`](DoubleOption::second_or) absence is not an error, `Empty` maps to `Ok(None)`.
+    /// This is the inverse of [`from_second_result`](DoubleOption::from_second_result).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.second_result(), Err(42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.second_result(), Ok(Some("second")));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.second_result(), Ok(None));
+    /// ```
+    #[inline]
+    pub fn second_result(self) -> Result<Option<U>, T> {
+        match self {
+            Self::Fist(first) => Err(first),
+            Self::Second(second) => Ok(Some(second)),
+            Self::Empty => Ok(None)
+        }
+    }
+
+    /// Creates a `DoubleOption` from a `Result<Option<T>, U>`, mapping [`Err`] to `Second`
+    /// and `Ok(None)` to `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// assert_eq!(DoubleOption::<i32, &str>::from_first_result(Ok(Some(42))), Fist(42));
+    /// assert_eq!(DoubleOption::<i32, &str>::from_first_result(Err("second")), Second("second"));
+    /// assert_eq!(DoubleOption::<i32, &str>::from_first_result(Ok(None)), Empty);
+    ///
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(DoubleOption::from_first_result(foo.first_result()), foo);
+    /// ```
+    #[inline]
+    pub fn from_first_result(result: Result<Option<T>, U>) -> Self {
+        match result {
+            Ok(Some(first)) => Self::Fist(first),
+            Err(second) => Self::Second(second),
+            Ok(None) => Self::Empty
+        }
+    }
+
+    /// Creates a `DoubleOption` from a `Result<Option<U>, T>`, mapping [`Err`] to `Fist`
+    /// and `Ok(None)` to `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// assert_eq!(DoubleOption::<i32, &str>::from_second_result(Err(42)), Fist(42));
+    /// assert_eq!(DoubleOption::<i32, &str>::from_second_result(Ok(Some("second"))), Second("second"));
+    /// assert_eq!(DoubleOption::<i32, &str>::from_second_result(Ok(None)), Empty);
+    ///
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(DoubleOption::from_second_result(foo.second_result()), foo);
+    /// ```
+    #[inline]
+    pub fn from_second_result(result: Result<Option<U>, T>) -> Self {
+        match result {
+            Err(first) => Self::Fist(first),
+            Ok(Some(second)) => Self::Second(second),
+            Ok(None) => Self::Empty
+        }
+    }
+
+    /////////////////////////////////////////////////////////////////////////
+    // Adapter for working with references
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Converts from `&DoubleOption<T, U>` to `DoubleOption<&T, &U>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<String, &str> = Fist("first".to_string());
+    /// assert_eq!(foo.as_ref(), Fist(&"first".to_string()));
+    ///
+    /// let bar: DoubleOption<String, &str> = Second("second");
+    /// assert_eq!(bar.as_ref(), Second(&"second"));
+    ///
+    /// let baz: DoubleOption<String, &str> = Empty;
+    /// assert_eq!(baz.as_ref(), Empty);
+    /// ```
+    ///
+    /// `as_ref` can be evaluated in const contexts:
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// const FOO: DoubleOption<i32, &str> = Fist(42);
+    /// const BAR: DoubleOption<i32, &str> = Second("second");
+    /// const BAZ: DoubleOption<i32, &str> = Empty;
+    ///
+    /// const _: () = {
+    ///     assert!(FOO.as_ref().is_first() && BAR.as_ref().is_second() && BAZ.as_ref().is_empty());
+    /// };
+    /// ```
+    #[inline]
+    pub const fn as_ref(&self) -> DoubleOption<&T, &U> {
+        match *self {
+            Self::Fist(ref first) => DoubleOption::Fist(first),
+            Self::Second(ref second) => DoubleOption::Second(second),
+            Self::Empty => DoubleOption::Empty
+        }
+    }
+
+    /// Converts from `&mut DoubleOption<T, U>` to `DoubleOption<&mut T, &mut U>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist};
+    /// let mut foo: DoubleOption<i32, &str> = Fist(42);
+    /// if let Fist(first) = foo.as_mut() {
+    ///     *first += 1;
+    /// }
+    /// assert_eq!(foo, Fist(43));
+    /// ```
+    #[inline]
+    pub const fn as_mut(&mut self) -> DoubleOption<&mut T, &mut U> {
+        match *self {
+            Self::Fist(ref mut first) => DoubleOption::Fist(first),
+            Self::Second(ref mut second) => DoubleOption::Second(second),
+            Self::Empty => DoubleOption::Empty
+        }
+    }
+
+    /// Converts from `Pin<&DoubleOption<T, U>>` to `DoubleOption<Pin<&T>, Pin<&U>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::pin::pin;
+    /// # use urt::doubleoption::DoubleOption::Fist;
+    /// let foo = pin!(Fist::<i32, &str>(42));
+    /// assert_eq!(foo.as_ref().as_pin_ref().map_first(|x| *x), Fist(42));
+    /// ```
+    #[inline]
+    pub fn as_pin_ref(self: Pin<&Self>) -> DoubleOption<Pin<&T>, Pin<&U>> {
+        // SAFETY: the payload is pinned because `self` is, it is never moved out.
+        unsafe {
+            match *Pin::get_ref(self) {
+                Self::Fist(ref first) => DoubleOption::Fist(Pin::new_unchecked(first)),
+                Self::Second(ref second) => DoubleOption::Second(Pin::new_unchecked(second)),
+                Self::Empty => DoubleOption::Empty
+            }
+        }
+    }
+
+    /// Converts from `Pin<&mut DoubleOption<T, U>>` to `DoubleOption<Pin<&mut T>, Pin<&mut U>>`.
+    ///
+    /// # Examples
+    ///
+    /// Polling whichever of two futures is present:
+    ///
+    /// ```
+    /// # use std::{future::{self, Future, Ready}, pin::{pin, Pin}, task::{Context, Poll, Waker}};
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// struct EitherFuture<A, B> {
+    ///     inner: DoubleOption<A, B>
+    /// }
+    ///
+    /// impl<A: Future<Output = i32>, B: Future<Output = i32>> Future for EitherFuture<A, B> {
+    ///     type Output = Option<i32>;
+    ///
+    ///     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<i32>> {
+    ///         // SAFETY: `inner` is structurally pinned, it is never moved out.
+    ///         let inner = unsafe { self.map_unchecked_mut(|x| &mut x.inner) };
+    ///         match inner.as_pin_mut() {
+    ///             Fist(a) => a.poll(cx).map(Some),
+    ///             Second(b) => b.poll(cx).map(Some),
+    ///             Empty => Poll::Ready(None)
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// let foo = pin!(EitherFuture::<Ready<i32>, Ready<i32>> { inner: Second(future::ready(42)) });
+    /// assert_eq!(foo.poll(&mut cx), Poll::Ready(Some(42)));
+    ///
+    /// let bar = pin!(EitherFuture::<Ready<i32>, Ready<i32>> { inner: Empty });
+    /// assert_eq!(bar.poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[inline]
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> DoubleOption<Pin<&mut T>, Pin<&mut U>> {
+        // SAFETY: the payload is pinned because `self` is, it is never moved out.
+        unsafe {
+            match *Pin::get_unchecked_mut(self) {
+                Self::Fist(ref mut first) => DoubleOption::Fist(Pin::new_unchecked(first)),
+                Self::Second(ref mut second) => DoubleOption::Second(Pin::new_unchecked(second)),
+                Self::Empty => DoubleOption::Empty
+            }
+        }
+    }
+
+    /// Converts from `&DoubleOption<T, U>` to an [`Option`] of `Double<&T, &U>`, where `Empty` maps to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::double::Double::{This, That};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.as_double(), Some(This(&42)));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.as_double(), Some(That(&"second")));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.as_double(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_double(&self) -> Option<Double<&T, &U>> {
+        match *self {
+            Self::Fist(ref first) => Some(Double::This(first)),
+            Self::Second(ref second) => Some(Double::That(second)),
+            Self::Empty => None
+        }
+    }
+
+    /// Returns a reference to the `Fist` value, or [`None`] for `Second` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.first_ref(), Some(&42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.first_ref(), None);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.first_ref(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn first_ref(&self) -> Option<&T> {
+        match *self {
+            Self::Fist(ref first) => Some(first),
+            _ => None
+        }
+    }
+
+    /// Returns a reference to the `Second` value, or [`None`] for `Fist` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.second_ref(), None);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.second_ref(), Some(&"second"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.second_ref(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn second_ref(&self) -> Option<&U> {
+        match *self {
+            Self::Second(ref second) => Some(second),
+            _ => None
+        }
+    }
+
+    /// Returns a mutable reference to the `Fist` value, or [`None`] for `Second` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut hits: DoubleOption<u32, &str> = Fist(0);
+    /// let label: DoubleOption<u32, &str> = Second("by name");
+    ///
+    /// if let (Some(count), Some(name)) = (hits.first_mut(), label.second_ref()) {
+    ///     *count += 1;
+    ///     assert_eq!(*name, "by name");
+    /// }
+    /// assert_eq!(hits, Fist(1));
+    ///
+    /// let mut bar: DoubleOption<u32, &str> = Second("second");
+    /// assert_eq!(bar.first_mut(), None);
+    ///
+    /// let mut baz: DoubleOption<u32, &str> = Empty;
+    /// assert_eq!(baz.first_mut(), None);
+    /// ```
+    #[inline]
+    pub const fn first_mut(&mut self) -> Option<&mut T> {
+        match *self {
+            Self::Fist(ref mut first) => Some(first),
+            _ => None
+        }
+    }
+
+    /// Returns a mutable reference to the `Second` value, or [`None`] for `Fist` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<u32, String> = Fist(42);
+    /// assert_eq!(foo.second_mut(), None);
+    ///
+    /// let mut bar: DoubleOption<u32, String> = Second("second".to_string());
+    /// if let Some(second) = bar.second_mut() {
+    ///     second.push('!');
+    /// }
+    /// assert_eq!(bar, Second("second!".to_string()));
+    ///
+    /// let mut baz: DoubleOption<u32, String> = Empty;
+    /// assert_eq!(baz.second_mut(), None);
+    /// ```
+    #[inline]
+    pub const fn second_mut(&mut self) -> Option<&mut U> {
+        match *self {
+            Self::Second(ref mut second) => Some(second),
+            _ => None
+        }
+    }
+
+    /////////////////////////////////////////////////////////////////////////
+    // Iterator constructors
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the possibly contained value as a [`Double`] of references.
+    ///
+    /// The iterator yields `This` for `Fist`, `That` for `Second` and nothing for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::double::Double::{This, That};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// let mut iter = foo.iter();
+    /// assert_eq!(iter.size_hint(), (1, Some(1)));
+    /// assert_eq!(iter.next(), Some(This(&42)));
+    /// assert_eq!(iter.size_hint(), (0, Some(0)));
+    /// assert_eq!(iter.next_back(), None);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.iter().next_back(), Some(That(&"second")));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.iter().len(), 0);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T, U> {
+        Iter { inner: self.as_double() }
+    }
+
+    /// Returns a mutable iterator over the possibly contained value as a [`Double`] of references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// # use urt::double::Double::{This, That};
+    /// let mut foo: DoubleOption<i32, String> = Fist(42);
+    /// for x in foo.iter_mut() {
+    ///     match x {
+    ///         This(first) => *first += 1,
+    ///         That(second) => second.push('!')
+    ///     }
+    /// }
+    /// assert_eq!(foo, Fist(43));
+    ///
+    /// let mut bar: DoubleOption<i32, String> = Empty;
+    /// assert_eq!(bar.iter_mut().size_hint(), (0, Some(0)));
+    ///
+    /// let mut baz: DoubleOption<i32, String> = Second("second".to_string());
+    /// assert!(matches!(baz.iter_mut().next(), Some(That(_))));
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, U> {
+        IterMut { inner: self.as_mut().into_double() }
+    }
+
+    /// Returns an iterator over the `Fist` value, yielding nothing for `Second` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// let mut iter = foo.iter_first();
+    /// assert_eq!(iter.size_hint(), (1, Some(1)));
+    /// assert_eq!(iter.next(), Some(&42));
+    /// assert_eq!(iter.size_hint(), (0, Some(0)));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.iter_first().size_hint(), (0, Some(0)));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.iter_first().len(), 0);
+    /// ```
+    #[inline]
+    pub fn iter_first(&self) -> ChannelIter<'_, T> {
+        ChannelIter { inner: self.as_ref().first() }
+    }
+
+    /// Returns an iterator over the `Second` value, yielding nothing for `Fist` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.iter_second().size_hint(), (0, Some(0)));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// let mut iter = bar.iter_second();
+    /// assert_eq!(iter.size_hint(), (1, Some(1)));
+    /// assert_eq!(iter.next_back(), Some(&"second"));
+    /// assert_eq!(iter.size_hint(), (0, Some(0)));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.iter_second().len(), 0);
+    /// ```
+    #[inline]
+    pub fn iter_second(&self) -> ChannelIter<'_, U> {
+        ChannelIter { inner: self.as_ref().second() }
+    }
+
+    /// Returns a mutable iterator over the `Fist` value, yielding nothing for `Second` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.iter_first_mut().size_hint(), (1, Some(1)));
+    /// foo.iter_first_mut().for_each(|first| *first += 1);
+    /// assert_eq!(foo, Fist(43));
+    ///
+    /// let mut bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.iter_first_mut().size_hint(), (0, Some(0)));
+    ///
+    /// let mut baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.iter_first_mut().len(), 0);
+    /// ```
+    #[inline]
+    pub fn iter_first_mut(&mut self) -> ChannelIterMut<'_, T> {
+        ChannelIterMut { inner: self.as_mut().first() }
+    }
+
+    /// Returns a mutable iterator over the `Second` value, yielding nothing for `Fist` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<i32, String> = Fist(42);
+    /// assert_eq!(foo.iter_second_mut().size_hint(), (0, Some(0)));
+    ///
+    /// let mut bar: DoubleOption<i32, String> = Second("second".to_string());
+    /// assert_eq!(bar.iter_second_mut().size_hint(), (1, Some(1)));
+    /// bar.iter_second_mut().for_each(|second| second.push('!'));
+    /// assert_eq!(bar, Second("second!".to_string()));
+    ///
+    /// let mut baz: DoubleOption<i32, String> = Empty;
+    /// assert_eq!(baz.iter_second_mut().len(), 0);
+    /// ```
+    #[inline]
+    pub fn iter_second_mut(&mut self) -> ChannelIterMut<'_, U> {
+        ChannelIterMut { inner: self.as_mut().second() }
+    }
+
+    /// Returns a consuming iterator over the `Fist` value, yielding nothing for `Second` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.into_iter_first().size_hint(), (1, Some(1)));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.into_iter_first().size_hint(), (0, Some(0)));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.into_iter_first().len(), 0);
+    ///
+    /// // Extracting one channel without partitioning.
+    /// let matches = vec![Fist(1), Empty, Second("two"), Fist(3)];
+    /// let ids: Vec<i32> = matches.into_iter().flat_map(DoubleOption::into_iter_first).collect();
+    /// assert_eq!(ids, [1, 3]);
+    /// ```
+    #[inline]
+    pub fn into_iter_first(self) -> ChannelIntoIter<T> {
+        ChannelIntoIter { inner: self.first() }
+    }
+
+    /// Returns a consuming iterator over the `Second` value, yielding nothing for `Fist` and `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.into_iter_second().size_hint(), (0, Some(0)));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.into_iter_second().size_hint(), (1, Some(1)));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.into_iter_second().len(), 0);
+    ///
+    /// let matches = vec![Fist(1), Empty, Second("two"), Fist(3)];
+    /// let names: Vec<&str> = matches.into_iter().flat_map(DoubleOption::into_iter_second).collect();
+    /// assert_eq!(names, ["two"]);
+    /// ```
+    #[inline]
+    pub fn into_iter_second(self) -> ChannelIntoIter<U> {
+        ChannelIntoIter { inner: self.second() }
+    }
+
+    /////////////////////////////////////////////////////////////////////////
+    // Getting to contained values
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Returns the contained `Fist` value, consuming the `self` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `Second` or `Empty`, naming the found variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.unwrap_first(), 42);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::doubleoption::DoubleOption::{self, Second};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// foo.unwrap_first(); // panics with "called `DoubleOption::unwrap_first()` on a `Second` value"
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_first(self) -> T {
+        match self {
+            Self::Fist(first) => first,
+            ref other => unwrap_failed("unwrap_first", other.variant_name())
+        }
+    }
+
+    /// Returns the contained `Second` value, consuming the `self` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `Fist` or `Empty`, naming the found variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Second};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.unwrap_second(), "second");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::doubleoption::DoubleOption::{self, Empty};
+    /// let foo: DoubleOption<i32, &str> = Empty;
+    /// foo.unwrap_second(); // panics with "called `DoubleOption::unwrap_second()` on a `Empty` value"
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_second(self) -> U {
+        match self {
+            Self::Second(second) => second,
+            ref other => unwrap_failed("unwrap_second", other.variant_name())
+        }
+    }
+
+    /// Returns the contained `Fist` value or the passed default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.unwrap_first_or(0), 42);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.unwrap_first_or(0), 0);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.unwrap_first_or(0), 0);
+    /// ```
+    #[inline]
+    pub fn unwrap_first_or(self, default: T) -> T {
+        match self {
+            Self::Fist(first) => first,
+            _ => default
+        }
+    }
+
+    /// Returns the contained `Fist` value or computes it from the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.unwrap_first_or_else(|| 0), 42);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.unwrap_first_or_else(|| 0), 0);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.unwrap_first_or_else(|| 0), 0);
+    /// ```
+    #[inline]
+    pub fn unwrap_first_or_else<F>(self, f: F) -> T
+    where
+        F: FnOnce() -> T
+    {
+        match self {
+            Self::Fist(first) => first,
+            _ => f()
+        }
+    }
+
+    /// Returns the contained `Fist` value or the default of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.unwrap_first_or_default(), 42);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.unwrap_first_or_default(), 0);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.unwrap_first_or_default(), 0);
+    /// ```
+    #[inline]
+    pub fn unwrap_first_or_default(self) -> T
+    where
+        T: Default
+    {
+        match self {
+            Self::Fist(first) => first,
+            _ => T::default()
+        }
+    }
+
+    /// Returns the contained `Second` value or the passed default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.unwrap_second_or("default"), "second");
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.unwrap_second_or("default"), "default");
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.unwrap_second_or("default"), "default");
+    /// ```
+    #[inline]
+    pub fn unwrap_second_or(self, default: U) -> U {
+        match self {
+            Self::Second(second) => second,
+            _ => default
+        }
+    }
+
+    /// Returns the contained `Second` value or computes it from the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.unwrap_second_or_else(|| "default"), "second");
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.unwrap_second_or_else(|| "default"), "default");
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.unwrap_second_or_else(|| "default"), "default");
+    /// ```
+    #[inline]
+    pub fn unwrap_second_or_else<F>(self, f: F) -> U
+    where
+        F: FnOnce() -> U
+    {
+        match self {
+            Self::Second(second) => second,
+            _ => f()
+        }
+    }
+
+    /// Returns the contained `Second` value or the default of `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.unwrap_second_or_default(), "second");
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.unwrap_second_or_default(), "");
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.unwrap_second_or_default(), "");
+    /// ```
+    #[inline]
+    pub fn unwrap_second_or_default(self) -> U
+    where
+        U: Default
+    {
+        match self {
+            Self::Second(second) => second,
+            _ => U::default()
+        }
+    }
+
+    /// Returns the contained `Fist` value, consuming the `self` value, without checking
+    /// that the value is not `Second` or `Empty`.
+    ///
+    /// This also covers `DoubleOption<&T, &U>` and `DoubleOption<&mut T, &mut U>`
+    /// obtained through [`as_ref`](DoubleOption::as_ref) and [`as_mut`](DoubleOption::as_mut).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on `Second` or `Empty` is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist};
+    /// let mut foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(unsafe { foo.as_ref().unwrap_first_unchecked() }, &42);
+    ///
+    /// *unsafe { foo.as_mut().unwrap_first_unchecked() } += 1;
+    /// assert_eq!(unsafe { foo.unwrap_first_unchecked() }, 43);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub unsafe fn unwrap_first_unchecked(self) -> T {
+        debug_assert!(self.is_first());
+        match self {
+            Self::Fist(first) => first,
+            _ => unsafe { hint::unreachable_unchecked() }
+        }
+    }
+
+    /// Returns the contained `Second` value, consuming the `self` value, without checking
+    /// that the value is not `Fist` or `Empty`.
+    ///
+    /// This also covers `DoubleOption<&T, &U>` and `DoubleOption<&mut T, &mut U>`
+    /// obtained through [`as_ref`](DoubleOption::as_ref) and [`as_mut`](DoubleOption::as_mut).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on `Fist` or `Empty` is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Second};
+    /// let mut foo: DoubleOption<i32, String> = Second("second".to_string());
+    /// assert_eq!(unsafe { foo.as_ref().unwrap_second_unchecked() }, "second");
+    ///
+    /// unsafe { foo.as_mut().unwrap_second_unchecked() }.push('!');
+    /// assert_eq!(unsafe { foo.unwrap_second_unchecked() }, "second!");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub unsafe fn unwrap_second_unchecked(self) -> U {
+        debug_assert!(self.is_second());
+        match self {
+            Self::Second(second) => second,
+            _ => unsafe { hint::unreachable_unchecked() }
+        }
+    }
+
+    /// Returns the contained `Fist` value, consuming the `self` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `Second` or `Empty`, with the passed message
+    /// and the found variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.expect_first("Testing expect"), 42);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// let panic = std::panic::catch_unwind(|| bar.expect_first("Testing expect")).unwrap_err();
+    /// # // `panic_immediate_abort` panics without a message.
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "Testing expect: Second(\"second\")");
+    /// # }
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// let panic = std::panic::catch_unwind(|| baz.expect_first("Testing expect")).unwrap_err();
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "Testing expect: Empty");
+    /// # }
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::doubleoption::DoubleOption::{self, Second};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// foo.expect_first("Testing expect"); // panics with `Testing expect: Second("second")`
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_first(self, msg: &str) -> T
+    where
+        U: Debug
+    {
+        match self {
+            Self::Fist(first) => first,
+            Self::Second(second) => expect_failed(msg, format_args!("Second({second:?})")),
+            Self::Empty => expect_failed(msg, format_args!("Empty"))
+        }
+    }
+
+    /// Returns the contained `Second` value, consuming the `self` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `Fist` or `Empty`, with the passed message
+    /// and the found variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.expect_second("Testing expect"), "second");
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// let panic = std::panic::catch_unwind(|| bar.expect_second("Testing expect")).unwrap_err();
+    /// # // `panic_immediate_abort` panics without a message.
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "Testing expect: Fist(42)");
+    /// # }
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// let panic = std::panic::catch_unwind(|| baz.expect_second("Testing expect")).unwrap_err();
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "Testing expect: Empty");
+    /// # }
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::doubleoption::DoubleOption::{self, Fist};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// foo.expect_second("Testing expect"); // panics with `Testing expect: Fist(42)`
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_second(self, msg: &str) -> U
+    where
+        T: Debug
+    {
+        match self {
+            Self::Second(second) => second,
+            Self::Fist(first) => expect_failed(msg, format_args!("Fist({first:?})")),
+            Self::Empty => expect_failed(msg, format_args!("Empty"))
+        }
+    }
+
+    /// Asserts that the value is `Empty`, consuming the `self` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `Fist` or `Second`, with the passed message
+    /// and the found variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Empty;
+    /// foo.expect_empty("Testing expect");
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// let panic = std::panic::catch_unwind(|| bar.expect_empty("Testing expect")).unwrap_err();
+    /// # // `panic_immediate_abort` panics without a message.
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "Testing expect: Fist(42)");
+    /// # }
+    ///
+    /// let baz: DoubleOption<i32, &str> = Second("second");
+    /// let panic = std::panic::catch_unwind(|| baz.expect_empty("Testing expect")).unwrap_err();
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "Testing expect: Second(\"second\")");
+    /// # }
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::doubleoption::DoubleOption::{self, Second};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// foo.expect_empty("Testing expect"); // panics with `Testing expect: Second("second")`
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_empty(self, msg: &str)
+    where
+        T: Debug,
+        U: Debug
+    {
+        match self {
+            Self::Empty => (),
+            Self::Fist(first) => expect_failed(msg, format_args!("Fist({first:?})")),
+            Self::Second(second) => expect_failed(msg, format_args!("Second({second:?})"))
+        }
+    }
+
+    /// Reduces the `DoubleOption` to a single value by applying `f` to a `Fist`
+    /// or `g` to a `Second` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.collapse(|x| x as usize, str::len), 42);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.collapse(|x| x as usize, str::len), 6);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::doubleoption::DoubleOption::{self, Empty};
+    /// let foo: DoubleOption<i32, &str> = Empty;
+    /// foo.collapse(|x| x as usize, str::len); // panics with "called `DoubleOption::collapse()` on a `Empty` value"
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn collapse<O, F, G>(self, f: F, g: G) -> O
+    where
+        F: FnOnce(T) -> O,
+        G: FnOnce(U) -> O
+    {
+        match self {
+            Self::Fist(first) => f(first),
+            Self::Second(second) => g(second),
+            Self::Empty => unwrap_failed("collapse", "Empty")
+        }
+    }
+
+    /// Reduces the `DoubleOption` to a single value by applying `f` to a `Fist`
+    /// or `g` to a `Second` value, returning `default` for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.collapse_or(|x| x as usize, str::len, 0), 42);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.collapse_or(|x| x as usize, str::len, 0), 6);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.collapse_or(|x| x as usize, str::len, 0), 0);
+    /// ```
+    #[inline]
+    pub fn collapse_or<O, F, G>(self, f: F, g: G, default: O) -> O
+    where
+        F: FnOnce(T) -> O,
+        G: FnOnce(U) -> O
+    {
+        match self {
+            Self::Fist(first) => f(first),
+            Self::Second(second) => g(second),
+            Self::Empty => default
+        }
+    }
+
+    /////////////////////////////////////////////////////////////////////////
+    // Transforming contained values
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Maps a `DoubleOption<T, U>` to `DoubleOption<O, U>` by applying a function
+    /// to a contained `Fist` value, leaving `Second` and `Empty` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.map_first(|x| x * 2), Fist(84));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.map_first(|x| x * 2), Second("second"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.map_first(|x| x * 2), Empty);
+    /// ```
+    #[inline]
+    pub fn map_first<O, F>(self, f: F) -> DoubleOption<O, U>
+    where
+        F: FnOnce(T) -> O
+    {
+        match self {
+            Self::Fist(first) => DoubleOption::Fist(f(first)),
+            Self::Second(second) => DoubleOption::Second(second),
+            Self::Empty => DoubleOption::Empty
+        }
+    }
+
+    /// Maps a `DoubleOption<T, U>` to `DoubleOption<T, R>` by applying a function
+    /// to a contained `Second` value, leaving `Fist` and `Empty` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.map_second(str::len), Second(6));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.map_second(str::len), Fist(42));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.map_second(str::len), Empty);
+    /// ```
+    #[inline]
+    pub fn map_second<R, G>(self, g: G) -> DoubleOption<T, R>
+    where
+        G: FnOnce(U) -> R
+    {
+        match self {
+            Self::Fist(first) => DoubleOption::Fist(first),
+            Self::Second(second) => DoubleOption::Second(g(second)),
+            Self::Empty => DoubleOption::Empty
+        }
+    }
+
+    /// Maps a `DoubleOption<T, U>` to `DoubleOption<O, R>` by applying `f` to a
+    /// contained `Fist` value or `g` to a contained `Second` value.
+    ///
+    /// `Empty` is passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.map(|x| x * 2, str::len), Fist(84));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.map(|x| x * 2, str::len), Second(6));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.map(|x| x * 2, str::len), Empty);
+    /// ```
+    #[inline]
+    pub fn map<F, G, O, R>(self, f: F, g: G) -> DoubleOption<O, R>
+    where
+        F: FnOnce(T) -> O,
+        G: FnOnce(U) -> R
+    {
+        match self {
+            Self::Fist(first) => DoubleOption::Fist(f(first)),
+            Self::Second(second) => DoubleOption::Second(g(second)),
+            Self::Empty => DoubleOption::Empty
+        }
+    }
+
+    /// Calls a function with a reference to the contained `Fist` value, if any, and returns `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let (mut hits, mut fallbacks, mut misses) = (0, 0, 0);
+    /// let lookups: [DoubleOption<i32, &str>; 4] = [Fist(1), Second("fallback"), Empty, Fist(2)];
+    ///
+    /// for lookup in lookups {
+    ///     let _ = lookup
+    ///         .inspect_first(|_| hits += 1)
+    ///         .inspect_second(|_| fallbacks += 1)
+    ///         .inspect_empty(|| misses += 1);
+    /// }
+    ///
+    /// assert_eq!((hits, fallbacks, misses), (2, 1, 1));
+    /// ```
+    #[must_use = "if you don't need the returned value, use `if let` instead"]
+    #[inline]
+    pub fn inspect_first<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T)
+    {
+        if let Self::Fist(ref first) = self {
+            f(first)
+        }
+
+        self
+    }
+
+    /// Calls a function with a reference to the contained `Second` value, if any, and returns `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut seen = Vec::new();
+    ///
+    /// let foo: [DoubleOption<i32, &str>; 3] = [Fist(1), Second("second"), Empty];
+    /// let bar: Vec<_> = foo.into_iter().map(|x| x.inspect_second(|x| seen.push(*x))).collect();
+    ///
+    /// assert_eq!(bar, foo);
+    ///
+    /// assert_eq!(seen, ["second"]);
+    /// ```
+    #[must_use = "if you don't need the returned value, use `if let` instead"]
+    #[inline]
+    pub fn inspect_second<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&U)
+    {
+        if let Self::Second(ref second) = self {
+            f(second)
+        }
+
+        self
+    }
+
+    /// Calls a function if the value is `Empty` and returns `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut misses = 0;
+    ///
+    /// let foo: [DoubleOption<i32, &str>; 3] = [Fist(1), Second("second"), Empty];
+    /// let bar: Vec<_> = foo.into_iter().map(|x| x.inspect_empty(|| misses += 1)).collect();
+    ///
+    /// assert_eq!(bar, foo);
+    ///
+    /// assert_eq!(misses, 1);
+    /// ```
+    #[must_use = "if you don't need the returned value, use `if let` instead"]
+    #[inline]
+    pub fn inspect_empty<F>(self, f: F) -> Self
+    where
+        F: FnOnce()
+    {
+        if let Self::Empty = self {
+            f()
+        }
+
+        self
+    }
+
+    /// Exchanges `Fist` and `Second`, leaving `Empty` untouched.
+    ///
+    /// This is the `DoubleOption` counterpart of [`Double::flip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.swap(), Second(42));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.swap(), Fist("second"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.swap(), Empty);
+    /// ```
+    #[inline]
+    pub fn swap(self) -> DoubleOption<U, T> {
+        match self {
+            Self::Fist(first) => DoubleOption::Second(first),
+            Self::Second(second) => DoubleOption::Fist(second),
+            Self::Empty => DoubleOption::Empty
+        }
+    }
+
+    /// Merges the `Second` channel into the `Fist` by converting its payload with `f`.
+    ///
+    /// Returns [`None`] only for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let legacy = |id: &str| id.trim_start_matches("legacy-").parse().unwrap();
+    ///
+    /// let foo: DoubleOption<u32, &str> = Fist(42);
+    /// assert_eq!(foo.coalesce(legacy), Some(42));
+    ///
+    /// let bar: DoubleOption<u32, &str> = Second("legacy-7");
+    /// assert_eq!(bar.coalesce(legacy), Some(7));
+    ///
+    /// let baz: DoubleOption<u32, &str> = Empty;
+    /// assert_eq!(baz.coalesce(legacy), None);
+    /// ```
+    #[inline]
+    pub fn coalesce<F>(self, f: F) -> Option<T>
+    where
+        F: FnOnce(U) -> T
+    {
+        match self {
+            Self::Fist(first) => Some(first),
+            Self::Second(second) => Some(f(second)),
+            Self::Empty => None
+        }
+    }
+
+    /// Merges the `Second` channel into the `Fist` using [`Into`].
+    ///
+    /// Returns [`None`] only for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<u64, u32> = Fist(42);
+    /// assert_eq!(foo.coalesce_into(), Some(42));
+    ///
+    /// let bar: DoubleOption<u64, u32> = Second(7);
+    /// assert_eq!(bar.coalesce_into(), Some(7));
+    ///
+    /// let baz: DoubleOption<u64, u32> = Empty;
+    /// assert_eq!(baz.coalesce_into(), None);
+    /// ```
+    #[inline]
+    pub fn coalesce_into(self) -> Option<T>
+    where
+        U: Into<T>
+    {
+        self.coalesce(Into::into)
+    }
+
+    /// Rewrites `Second` as `Fist` by converting its payload with `f`, keeping the `DoubleOption`.
+    ///
+    /// The result is never `Second`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<usize, &str> = Fist(42);
+    /// assert_eq!(foo.promote_second(str::len), Fist(42));
+    ///
+    /// let bar: DoubleOption<usize, &str> = Second("second");
+    /// assert_eq!(bar.promote_second(str::len), Fist(6));
+    ///
+    /// let baz: DoubleOption<usize, &str> = Empty;
+    /// assert_eq!(baz.promote_second(str::len), Empty);
+    /// ```
+    #[inline]
+    pub fn promote_second<F>(self, f: F) -> Self
+    where
+        F: FnOnce(U) -> T
+    {
+        match self {
+            Self::Second(second) => Self::Fist(f(second)),
+            other => other
+        }
+    }
+
+    /// Merges the `Second` channel into the `Fist` using [`Into`].
+    ///
+    /// This is the same as [`coalesce_into`](DoubleOption::coalesce_into).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<u64, u32> = Fist(42);
+    /// assert_eq!(foo.into_first(), Some(42));
+    ///
+    /// let bar: DoubleOption<u64, u32> = Second(7);
+    /// assert_eq!(bar.into_first(), Some(7));
+    ///
+    /// let baz: DoubleOption<u64, u32> = Empty;
+    /// assert_eq!(baz.into_first(), None);
+    /// ```
+    #[inline]
+    pub fn into_first(self) -> Option<T>
+    where
+        U: Into<T>
+    {
+        self.coalesce_into()
+    }
+
+    /// Normalizes `self` so it is never `Second`, converting a `Second` payload with `f`.
+    ///
+    /// This is the same as [`promote_second`](DoubleOption::promote_second).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<usize, &str> = Fist(42);
+    /// assert_eq!(foo.ensure_first(str::len), Fist(42));
+    ///
+    /// let bar: DoubleOption<usize, &str> = Second("second");
+    /// assert_eq!(bar.ensure_first(str::len), Fist(6));
+    ///
+    /// let baz: DoubleOption<usize, &str> = Empty;
+    /// assert_eq!(baz.ensure_first(str::len), Empty);
+    /// ```
+    #[inline]
+    pub fn ensure_first<F>(self, f: F) -> Self
+    where
+        F: FnOnce(U) -> T
+    {
+        self.promote_second(f)
+    }
+
+    /// Returns the provided default if the value is not `Fist`,
+    /// otherwise applies a function to the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.first_map_or(0, |x| x * 2), 84);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(bar.first_map_or(0, |x| x * 2), 0);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.first_map_or(0, |x| x * 2), 0);
+    /// ```
+    #[inline]
+    pub fn first_map_or<R, F>(self, default: R, f: F) -> R
+    where
+        F: FnOnce(T) -> R
+    {
+        match self {
+            Self::Fist(first) => f(first),
+            _ => default
+        }
+    }
+
+    /// Computes a default from the `Second` value, if any, if the value is not `Fist`,
+    /// otherwise applies a function to the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let fallback = |second: Option<&&str>| second.map_or(0, |s| s.len());
+    ///
+    /// let foo: DoubleOption<usize, &str> = Fist(42);
+    /// assert_eq!(foo.first_map_or_else(fallback, |x| x * 2), 84);
+    ///
+    /// let bar: DoubleOption<usize, &str> = Second("second");
+    /// assert_eq!(bar.first_map_or_else(fallback, |x| x * 2), 6);
+    ///
+    /// let baz: DoubleOption<usize, &str> = Empty;
+    /// assert_eq!(baz.first_map_or_else(fallback, |x| x * 2), 0);
+    /// ```
+    #[inline]
+    pub fn first_map_or_else<R, D, F>(self, default: D, f: F) -> R
+    where
+        D: FnOnce(Option<&U>) -> R,
+        F: FnOnce(T) -> R
+    {
+        match self {
+            Self::Fist(first) => f(first),
+            Self::Second(ref second) => default(Some(second)),
+            Self::Empty => default(None)
+        }
+    }
+
+    /// Returns the provided default if the value is not `Second`,
+    /// otherwise applies a function to the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.second_map_or(0, str::len), 6);
+    ///
+    /// let bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.second_map_or(0, str::len), 0);
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.second_map_or(0, str::len), 0);
+    /// ```
+    #[inline]
+    pub fn second_map_or<R, F>(self, default: R, f: F) -> R
+    where
+        F: FnOnce(U) -> R
+    {
+        match self {
+            Self::Second(second) => f(second),
+            _ => default
+        }
+    }
+
+    /// Computes a default from the `Fist` value, if any, if the value is not `Second`,
+    /// otherwise applies a function to the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let fallback = |first: Option<&usize>| first.copied().unwrap_or(0);
+    ///
+    /// let foo: DoubleOption<usize, &str> = Second("second");
+    /// assert_eq!(foo.second_map_or_else(fallback, str::len), 6);
+    ///
+    /// let bar: DoubleOption<usize, &str> = Fist(42);
+    /// assert_eq!(bar.second_map_or_else(fallback, str::len), 42);
+    ///
+    /// let baz: DoubleOption<usize, &str> = Empty;
+    /// assert_eq!(baz.second_map_or_else(fallback, str::len), 0);
+    /// ```
+    #[inline]
+    pub fn second_map_or_else<R, D, F>(self, default: D, f: F) -> R
+    where
+        D: FnOnce(Option<&T>) -> R,
+        F: FnOnce(U) -> R
+    {
+        match self {
+            Self::Second(second) => f(second),
+            Self::Fist(ref first) => default(Some(first)),
+            Self::Empty => default(None)
+        }
+    }
+
+    /////////////////////////////////////////////////////////////////////////
+    // Boolean operations on the values
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Returns `self` if it is not `Empty`, otherwise returns `other`.
+    ///
+    /// | `self`      | `other`     | result      |
+    /// |-------------|-------------|-------------|
+    /// | `Fist(a)`   | `Fist(b)`   | `Fist(a)`   |
+    /// | `Fist(a)`   | `Second(y)` | `Fist(a)`   |
+    /// | `Fist(a)`   | `Empty`     | `Fist(a)`   |
+    /// | `Second(x)` | `Fist(b)`   | `Second(x)` |
+    /// | `Second(x)` | `Second(y)` | `Second(x)` |
+    /// | `Second(x)` | `Empty`     | `Second(x)` |
+    /// | `Empty`     | `Fist(b)`   | `Fist(b)`   |
+    /// | `Empty`     | `Second(y)` | `Second(y)` |
+    /// | `Empty`     | `Empty`     | `Empty`     |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let a: DoubleOption<i32, &str> = Fist(1);
+    /// let x: DoubleOption<i32, &str> = Second("x");
+    /// let e: DoubleOption<i32, &str> = Empty;
+    /// let b: DoubleOption<i32, &str> = Fist(2);
+    /// let y: DoubleOption<i32, &str> = Second("y");
+    ///
+    /// assert_eq!(a.or(b), a);
+    /// assert_eq!(a.or(y), a);
+    /// assert_eq!(a.or(e), a);
+    /// assert_eq!(x.or(b), x);
+    /// assert_eq!(x.or(y), x);
+    /// assert_eq!(x.or(e), x);
+    /// assert_eq!(e.or(b), b);
+    /// assert_eq!(e.or(y), y);
+    /// assert_eq!(e.or(e), e);
+    /// ```
+    #[inline]
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::Empty => other,
+            _ => self
+        }
+    }
+
+    /// Returns `self` if it is not `Empty`, otherwise calls `f` and returns the result.
+    ///
+    /// Follows the same table as [`or`](DoubleOption::or), but `other` is only computed for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(1);
+    /// assert_eq!(foo.or_else(|| unreachable!()), Fist(1));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("x");
+    /// assert_eq!(bar.or_else(|| unreachable!()), Second("x"));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(baz.or_else(|| Second("y")), Second("y"));
+    /// ```
+    #[inline]
+    pub fn or_else<F>(self, f: F) -> Self
+    where
+        F: FnOnce() -> Self
+    {
+        match self {
+            Self::Empty => f(),
+            _ => self
+        }
+    }
+
+    /// Fills an `Empty` with `Fist(value)`, `Fist` and `Second` are returned untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<u16, &str> = Fist(443);
+    /// assert_eq!(foo.or_first(8080), Fist(443));
+    ///
+    /// let bar: DoubleOption<u16, &str> = Second("https");
+    /// assert_eq!(bar.or_first(8080), Second("https"));
+    ///
+    /// let baz: DoubleOption<u16, &str> = Empty;
+    /// assert_eq!(baz.or_first(8080), Fist(8080));
+    /// ```
+    #[inline]
+    pub fn or_first(self, value: T) -> Self {
+        match self {
+            Self::Empty => Self::Fist(value),
+            _ => self
+        }
+    }
+
+    /// Fills an `Empty` with `Second(value)`, `Fist` and `Second` are returned untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<u16, &str> = Fist(443);
+    /// assert_eq!(foo.or_second("http"), Fist(443));
+    ///
+    /// let bar: DoubleOption<u16, &str> = Second("https");
+    /// assert_eq!(bar.or_second("http"), Second("https"));
+    ///
+    /// let baz: DoubleOption<u16, &str> = Empty;
+    /// assert_eq!(baz.or_second("http"), Second("http"));
+    /// ```
+    #[inline]
+    pub fn or_second(self, value: U) -> Self {
+        match self {
+            Self::Empty => Self::Second(value),
+            _ => self
+        }
+    }
+
+    /// Fills an `Empty` with `Fist(f())`, `Fist` and `Second` are returned untouched
+    /// without calling `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<u16, &str> = Fist(443);
+    /// assert_eq!(foo.or_first_with(|| unreachable!()), Fist(443));
+    ///
+    /// let bar: DoubleOption<u16, &str> = Second("https");
+    /// assert_eq!(bar.or_first_with(|| unreachable!()), Second("https"));
+    ///
+    /// let baz: DoubleOption<u16, &str> = Empty;
+    /// assert_eq!(baz.or_first_with(|| 8080).map_first(|port| port + 1), Fist(8081));
+    /// ```
+    #[inline]
+    pub fn or_first_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce() -> T
+    {
+        match self {
+            Self::Empty => Self::Fist(f()),
+            _ => self
+        }
+    }
+
+    /// Fills an `Empty` with `Second(f())`, `Fist` and `Second` are returned untouched
+    /// without calling `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<u16, &str> = Fist(443);
+    /// assert_eq!(foo.or_second_with(|| unreachable!()), Fist(443));
+    ///
+    /// let bar: DoubleOption<u16, &str> = Second("https");
+    /// assert_eq!(bar.or_second_with(|| unreachable!()), Second("https"));
+    ///
+    /// let baz: DoubleOption<u16, &str> = Empty;
+    /// assert_eq!(baz.or_second_with(|| "http"), Second("http"));
+    /// ```
+    #[inline]
+    pub fn or_second_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce() -> U
+    {
+        match self {
+            Self::Empty => Self::Second(f()),
+            _ => self
+        }
+    }
+
+    /// Returns `Empty` if `self` is `Empty`, otherwise returns `other`.
+    ///
+    /// The payload of `self` is always dropped; only the payload of `other` survives.
+    ///
+    /// | `self`      | `other`     | result      |
+    /// |-------------|-------------|-------------|
+    /// | `Fist(a)`   | `Fist(b)`   | `Fist(b)`   |
+    /// | `Fist(a)`   | `Second(y)` | `Second(y)` |
+    /// | `Fist(a)`   | `Empty`     | `Empty`     |
+    /// | `Second(x)` | `Fist(b)`   | `Fist(b)`   |
+    /// | `Second(x)` | `Second(y)` | `Second(y)` |
+    /// | `Second(x)` | `Empty`     | `Empty`     |
+    /// | `Empty`     | `Fist(b)`   | `Empty`     |
+    /// | `Empty`     | `Second(y)` | `Empty`     |
+    /// | `Empty`     | `Empty`     | `Empty`     |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let a: DoubleOption<i32, &str> = Fist(1);
+    /// let x: DoubleOption<i32, &str> = Second("x");
+    /// let e: DoubleOption<i32, &str> = Empty;
+    /// let b: DoubleOption<char, bool> = Fist('b');
+    /// let y: DoubleOption<char, bool> = Second(true);
+    /// let f: DoubleOption<char, bool> = Empty;
+    ///
+    /// assert_eq!(a.and(b), b);
+    /// assert_eq!(a.and(y), y);
+    /// assert_eq!(a.and(f), f);
+    /// assert_eq!(x.and(b), b);
+    /// assert_eq!(x.and(y), y);
+    /// assert_eq!(x.and(f), f);
+    /// assert_eq!(e.and(b), f);
+    /// assert_eq!(e.and(y), f);
+    /// assert_eq!(e.and(f), f);
+    /// ```
+    #[inline]
+    pub fn and<O, R>(self, other: DoubleOption<O, R>) -> DoubleOption<O, R> {
+        match self {
+            Self::Empty => DoubleOption::Empty,
+            _ => other
+        }
+    }
+
+    /// Returns whichever of `self` and `other` is not `Empty` if exactly one is, otherwise `Empty`.
+    ///
+    /// | `self`      | `other`     | result      |
+    /// |-------------|-------------|-------------|
+    /// | `Fist(a)`   | `Fist(b)`   | `Empty`     |
+    /// | `Fist(a)`   | `Second(y)` | `Empty`     |
+    /// | `Fist(a)`   | `Empty`     | `Fist(a)`   |
+    /// | `Second(x)` | `Fist(b)`   | `Empty`     |
+    /// | `Second(x)` | `Second(y)` | `Empty`     |
+    /// | `Second(x)` | `Empty`     | `Second(x)` |
+    /// | `Empty`     | `Fist(b)`   | `Fist(b)`   |
+    /// | `Empty`     | `Second(y)` | `Second(y)` |
+    /// | `Empty`     | `Empty`     | `Empty`     |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let a: DoubleOption<i32, &str> = Fist(1);
+    /// let x: DoubleOption<i32, &str> = Second("x");
+    /// let e: DoubleOption<i32, &str> = Empty;
+    /// let b: DoubleOption<i32, &str> = Fist(2);
+    /// let y: DoubleOption<i32, &str> = Second("y");
+    ///
+    /// assert_eq!(a.xor(b), Empty);
+    /// assert_eq!(a.xor(y), Empty);
+    /// assert_eq!(a.xor(e), a);
+    /// assert_eq!(x.xor(b), Empty);
+    /// assert_eq!(x.xor(y), Empty);
+    /// assert_eq!(x.xor(e), x);
+    /// assert_eq!(e.xor(b), b);
+    /// assert_eq!(e.xor(y), y);
+    /// assert_eq!(e.xor(e), Empty);
+    /// ```
+    #[inline]
+    pub fn xor(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Empty, other) => other,
+            (this, Self::Empty) => this,
+            _ => Self::Empty
+        }
+    }
+
+    /// Merges `self` and `other`, picking the preferred channel if they disagree.
+    ///
+    /// A non-`Empty` operand beats an `Empty` one. If one operand is `Fist` and the
+    /// other is `Second`, `Fist` wins if `prefer_first_channel` is `true` and `Second`
+    /// otherwise. If both hold the same variant `self` wins, so earlier layers
+    /// override later ones.
+    ///
+    /// | `self`      | `other`     | `prefer_first_channel = true` | `prefer_first_channel = false` |
+    /// |-------------|-------------|-------------------------------|--------------------------------|
+    /// | `Fist(a)`   | `Fist(b)`   | `Fist(a)`                     | `Fist(a)`                      |
+    /// | `Fist(a)`   | `Second(y)` | `Fist(a)`                     | `Second(y)`                    |
+    /// | `Fist(a)`   | `Empty`     | `Fist(a)`                     | `Fist(a)`                      |
+    /// | `Second(x)` | `Fist(b)`   | `Fist(b)`                     | `Second(x)`                    |
+    /// | `Second(x)` | `Second(y)` | `Second(x)`                   | `Second(x)`                    |
+    /// | `Second(x)` | `Empty`     | `Second(x)`                   | `Second(x)`                    |
+    /// | `Empty`     | `Fist(b)`   | `Fist(b)`                     | `Fist(b)`                      |
+    /// | `Empty`     | `Second(y)` | `Second(y)`                   | `Second(y)`                    |
+    /// | `Empty`     | `Empty`     | `Empty`                       | `Empty`                        |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let a: DoubleOption<i32, &str> = Fist(1);
+    /// let x: DoubleOption<i32, &str> = Second("x");
+    /// let e: DoubleOption<i32, &str> = Empty;
+    /// let b: DoubleOption<i32, &str> = Fist(2);
+    /// let y: DoubleOption<i32, &str> = Second("y");
+    ///
+    /// assert_eq!(a.prefer(b, true), a);
+    /// assert_eq!(a.prefer(y, true), a);
+    /// assert_eq!(a.prefer(e, true), a);
+    /// assert_eq!(x.prefer(b, true), b);
+    /// assert_eq!(x.prefer(y, true), x);
+    /// assert_eq!(x.prefer(e, true), x);
+    /// assert_eq!(e.prefer(b, true), b);
+    /// assert_eq!(e.prefer(y, true), y);
+    /// assert_eq!(e.prefer(e, true), e);
+    ///
+    /// assert_eq!(a.prefer(b, false), a);
+    /// assert_eq!(a.prefer(y, false), y);
+    /// assert_eq!(a.prefer(e, false), a);
+    /// assert_eq!(x.prefer(b, false), x);
+    /// assert_eq!(x.prefer(y, false), x);
+    /// assert_eq!(x.prefer(e, false), x);
+    /// assert_eq!(e.prefer(b, false), b);
+    /// assert_eq!(e.prefer(y, false), y);
+    /// assert_eq!(e.prefer(e, false), e);
+    /// ```
+    ///
+    /// Layering configuration sources, where each may give a port number or a service name:
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let cli: DoubleOption<u16, &str> = Empty;
+    /// let env: DoubleOption<u16, &str> = Second("http");
+    /// let file: DoubleOption<u16, &str> = Fist(8080);
+    ///
+    /// let port = [cli, env, file].into_iter().fold(Empty, |acc, layer| acc.prefer(layer, true));
+    /// assert_eq!(port, Fist(8080));
+    /// ```
+    #[inline]
+    pub fn prefer(self, other: Self, prefer_first_channel: bool) -> Self {
+        match (self, other) {
+            (Self::Empty, other) => other,
+            (this, Self::Empty) => this,
+            (Self::Fist(first), Self::Second(_)) | (Self::Second(_), Self::Fist(first)) if prefer_first_channel => {
+                Self::Fist(first)
+            },
+            (Self::Fist(_), Self::Second(second)) | (Self::Second(second), Self::Fist(_)) => Self::Second(second),
+            (this, _) => this
+        }
+    }
+
+    /// Zips `self` with another `DoubleOption` if both hold the same variant.
+    ///
+    /// Any mismatch or `Empty` operand yields `Empty`.
+    ///
+    /// | `self`      | `other`     | result           |
+    /// |-------------|-------------|------------------|
+    /// | `Fist(a)`   | `Fist(b)`   | `Fist((a, b))`   |
+    /// | `Fist(a)`   | `Second(y)` | `Empty`          |
+    /// | `Fist(a)`   | `Empty`     | `Empty`          |
+    /// | `Second(x)` | `Fist(b)`   | `Empty`          |
+    /// | `Second(x)` | `Second(y)` | `Second((x, y))` |
+    /// | `Second(x)` | `Empty`     | `Empty`          |
+    /// | `Empty`     | `Fist(b)`   | `Empty`          |
+    /// | `Empty`     | `Second(y)` | `Empty`          |
+    /// | `Empty`     | `Empty`     | `Empty`          |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let a: DoubleOption<i32, &str> = Fist(1);
+    /// let x: DoubleOption<i32, &str> = Second("x");
+    /// let e: DoubleOption<i32, &str> = Empty;
+    /// let b: DoubleOption<char, bool> = Fist('b');
+    /// let y: DoubleOption<char, bool> = Second(true);
+    /// let f: DoubleOption<char, bool> = Empty;
+    ///
+    /// assert_eq!(a.zip(b), Fist((1, 'b')));
+    /// assert_eq!(a.zip(y), Empty);
+    /// assert_eq!(a.zip(f), Empty);
+    /// assert_eq!(x.zip(b), Empty);
+    /// assert_eq!(x.zip(y), Second(("x", true)));
+    /// assert_eq!(x.zip(f), Empty);
+    /// assert_eq!(e.zip(b), Empty);
+    /// assert_eq!(e.zip(y), Empty);
+    /// assert_eq!(e.zip(f), Empty);
+    /// ```
+    #[inline]
+    pub fn zip<T2, U2>(self, other: DoubleOption<T2, U2>) -> DoubleOption<(T, T2), (U, U2)> {
+        self.zip_with(other, |a, b| (a, b), |x, y| (x, y))
+    }
+
+    /// Zips `self` with another `DoubleOption` if both hold the same variant,
+    /// combining two `Fist` values with `f` and two `Second` values with `g`.
+    ///
+    /// Follows the same table as [`zip`](DoubleOption::zip).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<i32, &str> = Fist(2);
+    /// assert_eq!(foo.zip_with(Fist(3), |a, b| a * b, |x: &str, y: &str| x.len() + y.len()), Fist(6));
+    ///
+    /// let bar: DoubleOption<i32, &str> = Second("ab");
+    /// assert_eq!(bar.zip_with(Second("c"), |a, b: i32| a * b, |x, y| x.len() + y.len()), Second(3));
+    ///
+    /// let baz: DoubleOption<i32, &str> = Fist(2);
+    /// assert_eq!(baz.zip_with(Second("c"), |a, b: i32| a * b, |x, y| x.len() + y.len()), Empty);
+    /// ```
+    #[inline]
+    pub fn zip_with<T2, U2, O, R, F, G>(self, other: DoubleOption<T2, U2>, f: F, g: G) -> DoubleOption<O, R>
+    where
+        F: FnOnce(T, T2) -> O,
+        G: FnOnce(U, U2) -> R
+    {
+        match (self, other) {
+            (Self::Fist(a), DoubleOption::Fist(b)) => DoubleOption::Fist(f(a, b)),
+            (Self::Second(x), DoubleOption::Second(y)) => DoubleOption::Second(g(x, y)),
+            _ => DoubleOption::Empty
+        }
+    }
+
+    /// Takes the value out of the `DoubleOption`, leaving `Empty` in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Second, Empty};
+    /// let mut foo: DoubleOption<i32, &str> = Second("second");
+    /// assert_eq!(foo.take(), Second("second"));
+    /// assert_eq!(foo, Empty);
+    /// ```
+    #[inline]
+    pub fn take(&mut self) -> Self {
+        mem::take(self)
+    }
+
+    /// Takes the `Fist` value out of the `DoubleOption`, leaving `Empty` in its place.
+    ///
+    /// `Second` and `Empty` are left untouched and [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// struct Counted(Rc<Cell<u32>>);
+    ///
+    /// impl Drop for Counted {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Rc::new(Cell::new(0));
+    ///
+    /// let mut foo: DoubleOption<Counted, Counted> = Fist(Counted(drops.clone()));
+    /// let first = foo.take_first();
+    /// assert!(first.is_some() && matches!(foo, Empty));
+    /// assert_eq!(drops.get(), 0);
+    /// drop(first);
+    /// assert_eq!(drops.get(), 1);
+    ///
+    /// let mut bar: DoubleOption<Counted, Counted> = Second(Counted(drops.clone()));
+    /// assert!(bar.take_first().is_none());
+    /// assert!(matches!(bar, Second(_)));
+    /// assert_eq!(drops.get(), 1);
+    /// drop(bar);
+    /// assert_eq!(drops.get(), 2);
+    /// ```
+    #[inline]
+    pub fn take_first(&mut self) -> Option<T> {
+        if self.is_first() {
+            self.take().first()
+        } else {
+            None
+        }
+    }
+
+    /// Takes the `Second` value out of the `DoubleOption`, leaving `Empty` in its place.
+    ///
+    /// `Fist` and `Empty` are left untouched and [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// struct Counted(Rc<Cell<u32>>);
+    ///
+    /// impl Drop for Counted {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Rc::new(Cell::new(0));
+    ///
+    /// let mut foo: DoubleOption<Counted, Counted> = Second(Counted(drops.clone()));
+    /// let second = foo.take_second();
+    /// assert!(second.is_some() && matches!(foo, Empty));
+    /// assert_eq!(drops.get(), 0);
+    /// drop(second);
+    /// assert_eq!(drops.get(), 1);
+    ///
+    /// let mut bar: DoubleOption<Counted, Counted> = Fist(Counted(drops.clone()));
+    /// assert!(bar.take_second().is_none());
+    /// assert!(matches!(bar, Fist(_)));
+    /// assert_eq!(drops.get(), 1);
+    /// drop(bar);
+    /// assert_eq!(drops.get(), 2);
+    /// ```
+    #[inline]
+    pub fn take_second(&mut self) -> Option<U> {
+        if self.is_second() {
+            self.take().second()
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the value in the `DoubleOption` with `other`, returning the old value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second};
+    /// let mut foo: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(foo.replace(Second("second")), Fist(42));
+    /// assert_eq!(foo, Second("second"));
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, other: Self) -> Self {
+        mem::replace(self, other)
+    }
+
+    /// Overwrites the `DoubleOption` with `Fist(value)`, returning the previous state.
+    ///
+    /// The displaced payload is handed back instead of being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use urt::doubleoption::DoubleOption::{self, Second, Empty};
+    /// struct Counted(Rc<Cell<u32>>);
+    ///
+    /// impl Drop for Counted {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Rc::new(Cell::new(0));
+    /// let mut foo: DoubleOption<Counted, Counted> = Empty;
+    ///
+    /// assert!(foo.set_first(Counted(drops.clone())).is_empty());
+    /// assert!(foo.is_first());
+    ///
+    /// // Overwriting `Fist` hands back the old payload.
+    /// let old = foo.set_first(Counted(drops.clone()));
+    /// assert!(old.is_first());
+    /// assert_eq!(drops.get(), 0);
+    /// drop(old);
+    /// assert_eq!(drops.get(), 1);
+    ///
+    /// // Overwriting `Second` does too.
+    /// foo = Second(Counted(drops.clone()));
+    /// assert_eq!(drops.get(), 2);
+    /// let old = foo.set_first(Counted(drops.clone()));
+    /// assert!(old.is_second());
+    /// assert_eq!(drops.get(), 2);
+    /// drop(old);
+    /// assert_eq!(drops.get(), 3);
+    ///
+    /// drop(foo);
+    /// assert_eq!(drops.get(), 4);
+    /// ```
+    #[inline]
+    pub fn set_first(&mut self, value: T) -> Self {
+        mem::replace(self, Self::Fist(value))
+    }
+
+    /// Overwrites the `DoubleOption` with `Second(value)`, returning the previous state.
+    ///
+    /// The displaced payload is handed back instead of being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Empty};
+    /// struct Counted(Rc<Cell<u32>>);
+    ///
+    /// impl Drop for Counted {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Rc::new(Cell::new(0));
+    /// let mut foo: DoubleOption<Counted, Counted> = Empty;
+    ///
+    /// assert!(foo.set_second(Counted(drops.clone())).is_empty());
+    /// assert!(foo.is_second());
+    ///
+    /// // Overwriting `Second` hands back the old payload.
+    /// let old = foo.set_second(Counted(drops.clone()));
+    /// assert!(old.is_second());
+    /// assert_eq!(drops.get(), 0);
+    /// drop(old);
+    /// assert_eq!(drops.get(), 1);
+    ///
+    /// // Overwriting `Fist` does too.
+    /// foo = Fist(Counted(drops.clone()));
+    /// assert_eq!(drops.get(), 2);
+    /// let old = foo.set_second(Counted(drops.clone()));
+    /// assert!(old.is_first());
+    /// assert_eq!(drops.get(), 2);
+    /// drop(old);
+    /// assert_eq!(drops.get(), 3);
+    ///
+    /// drop(foo);
+    /// assert_eq!(drops.get(), 4);
+    /// ```
+    #[inline]
+    pub fn set_second(&mut self, value: U) -> Self {
+        mem::replace(self, Self::Second(value))
+    }
+
+    /// Modifies the `Fist` value in place with `f`, `Second` and `Empty` are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<Vec<i32>, &str> = Fist(vec![1, 2]);
+    /// foo.map_first_in_place(|first| first.push(3));
+    /// assert_eq!(foo, Fist(vec![1, 2, 3]));
+    ///
+    /// let mut bar: DoubleOption<Vec<i32>, &str> = Second("second");
+    /// bar.map_first_in_place(|_| unreachable!());
+    /// assert_eq!(bar, Second("second"));
+    ///
+    /// let mut baz: DoubleOption<Vec<i32>, &str> = Empty;
+    /// baz.map_first_in_place(|_| unreachable!());
+    /// assert_eq!(baz, Empty);
+    /// ```
+    #[inline]
+    pub fn map_first_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut T)
+    {
+        if let Some(first) = self.first_mut() {
+            f(first);
+        }
+    }
+
+    /// Inserts `value` as `Fist`, then returns a mutable reference to it.
+    ///
+    /// Any previous value is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second};
+    /// let mut foo: DoubleOption<i32, &str> = Second("second");
+    /// let first = foo.insert_first(1);
+    /// assert_eq!(*first, 1);
+    /// *first = 3;
+    /// assert_eq!(foo, Fist(3));
+    /// ```
+    #[must_use = "if you intended to set a value, consider assignment instead"]
+    #[inline]
+    pub fn insert_first(&mut self, value: T) -> &mut T {
+        *self = Self::Fist(value);
+        unsafe { self.as_mut().unwrap_first_unchecked() }
+    }
+
+    /// Inserts `value` as `Second`, then returns a mutable reference to it.
+    ///
+    /// Any previous value is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second};
+    /// let mut foo: DoubleOption<i32, &str> = Fist(42);
+    /// let second = foo.insert_second("second");
+    /// assert_eq!(*second, "second");
+    /// *second = "other";
+    /// assert_eq!(foo, Second("other"));
+    /// ```
+    #[must_use = "if you intended to set a value, consider assignment instead"]
+    #[inline]
+    pub fn insert_second(&mut self, value: U) -> &mut U {
+        *self = Self::Second(value);
+        unsafe { self.as_mut().unwrap_second_unchecked() }
+    }
+
+    /// Inserts `value` as `Fist` if the value is not `Fist`, then returns a mutable reference to it.
+    ///
+    /// A `Second` value is replaced and dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<i32, &str> = Empty;
+    /// {
+    ///     let first: &mut i32 = foo.get_or_insert_first(5);
+    ///     assert_eq!(first, &5);
+    ///
+    ///     *first = 7;
+    /// }
+    /// assert_eq!(foo, Fist(7));
+    /// assert_eq!(foo.get_or_insert_first(1), &7);
+    ///
+    /// struct Counted(Rc<Cell<u32>>);
+    ///
+    /// impl Drop for Counted {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Rc::new(Cell::new(0));
+    /// let mut bar: DoubleOption<Counted, Counted> = Second(Counted(drops.clone()));
+    /// bar.get_or_insert_first(Counted(drops.clone()));
+    /// assert_eq!(drops.get(), 1);
+    ///
+    /// bar.get_or_insert_first(Counted(drops.clone()));
+    /// assert_eq!(drops.get(), 2);
+    ///
+    /// drop(bar);
+    /// assert_eq!(drops.get(), 3);
+    /// ```
+    #[inline]
+    pub fn get_or_insert_first(&mut self, value: T) -> &mut T {
+        if !self.is_first() {
+            *self = Self::Fist(value);
+        }
+
+        unsafe { self.as_mut().unwrap_first_unchecked() }
+    }
+
+    /// Inserts a value computed from `f` as `Fist` if the value is not `Fist`,
+    /// then returns a mutable reference to it.
+    ///
+    /// A `Second` value is replaced and dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second};
+    /// let mut foo: DoubleOption<i32, &str> = Second("second");
+    /// {
+    ///     let first: &mut i32 = foo.get_or_insert_first_with(|| 5);
+    ///     assert_eq!(first, &5);
+    ///
+    ///     *first = 7;
+    /// }
+    /// assert_eq!(foo, Fist(7));
+    /// assert_eq!(foo.get_or_insert_first_with(|| unreachable!()), &7);
+    /// ```
+    #[inline]
+    pub fn get_or_insert_first_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T
+    {
+        if !self.is_first() {
+            *self = Self::Fist(f());
+        }
+
+        unsafe { self.as_mut().unwrap_first_unchecked() }
+    }
+
+    /// Inserts `value` as `Second` if the value is not `Second`, then returns a mutable reference to it.
+    ///
+    /// A `Fist` value is replaced and dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<i32, &str> = Empty;
+    /// {
+    ///     let second: &mut &str = foo.get_or_insert_second("second");
+    ///     assert_eq!(second, &"second");
+    ///
+    ///     *second = "other";
+    /// }
+    /// assert_eq!(foo, Second("other"));
+    /// assert_eq!(foo.get_or_insert_second("unused"), &"other");
+    ///
+    /// let mut bar: DoubleOption<i32, &str> = Fist(42);
+    /// assert_eq!(bar.get_or_insert_second("second"), &"second");
+    /// assert_eq!(bar, Second("second"));
+    /// ```
+    #[inline]
+    pub fn get_or_insert_second(&mut self, value: U) -> &mut U {
+        if !self.is_second() {
+            *self = Self::Second(value);
+        }
+
+        unsafe { self.as_mut().unwrap_second_unchecked() }
+    }
+
+    /// Inserts a value computed from `f` as `Second` if the value is not `Second`,
+    /// then returns a mutable reference to it.
+    ///
+    /// A `Fist` value is replaced and dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<i32, &str> = Fist(42);
+    /// {
+    ///     let second: &mut &str = foo.get_or_insert_second_with(|| "second");
+    ///     assert_eq!(second, &"second");
+    ///
+    ///     *second = "other";
+    /// }
+    /// assert_eq!(foo, Second("other"));
+    /// assert_eq!(foo.get_or_insert_second_with(|| unreachable!()), &"other");
+    ///
+    /// let mut bar: DoubleOption<i32, &str> = Empty;
+    /// assert_eq!(bar.get_or_insert_second_with(|| "second"), &"second");
+    /// ```
+    #[inline]
+    pub fn get_or_insert_second_with<F>(&mut self, f: F) -> &mut U
+    where
+        F: FnOnce() -> U
+    {
+        if !self.is_second() {
+            *self = Self::Second(f());
+        }
+
+        unsafe { self.as_mut().unwrap_second_unchecked() }
+    }
+}
+
+// This is a separate function to reduce the code size of the unwrap_* methods.
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn unwrap_failed(method: &str, found: &str) -> ! {
+    panic!("called `DoubleOption::{method}()` on a `{found}` value")
+}
+
+// This is a separate function to reduce the code size of the expect_* methods.
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn expect_failed(msg: &str, found: fmt::Arguments<'_>) -> ! {
+    #[cfg(feature = "panic_immediate_abort")]
+    {
+        let _ = (msg, found);
+        panic!()
+    }
+    #[cfg(not(feature = "panic_immediate_abort"))]
+    panic!("{msg}: {found}")
+}
+
+impl<A, B, C, D> DoubleOption<(A, B), (C, D)> {
+    /// Splits a `DoubleOption` of tuples into two `DoubleOption`s sharing the original variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<(usize, char), (usize, &str)> = Fist((3, 'a'));
+    /// assert_eq!(foo.unzip(), (Fist(3), Fist('a')));
+    ///
+    /// let bar: DoubleOption<(usize, char), (usize, &str)> = Second((7, "b"));
+    /// assert_eq!(bar.unzip(), (Second(7), Second("b")));
+    ///
+    /// let baz: DoubleOption<(usize, char), (usize, &str)> = Empty;
+    /// assert_eq!(baz.unzip(), (Empty, Empty));
+    /// ```
+    #[inline]
+    pub fn unzip(self) -> (DoubleOption<A, C>, DoubleOption<B, D>) {
+        match self {
+            Self::Fist((a, b)) => (DoubleOption::Fist(a), DoubleOption::Fist(b)),
+            Self::Second((c, d)) => (DoubleOption::Second(c), DoubleOption::Second(d)),
+            Self::Empty => (DoubleOption::Empty, DoubleOption::Empty)
+        }
+    }
+}
+
+impl<T> DoubleOption<T, T> {
+    /// Exchanges `Fist` and `Second` in place, leaving `Empty` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let mut foo: DoubleOption<i32, i32> = Fist(42);
+    /// foo.swap_in_place();
+    /// assert_eq!(foo, Second(42));
+    ///
+    /// let mut bar: DoubleOption<i32, i32> = Second(7);
+    /// bar.swap_in_place();
+    /// assert_eq!(bar, Fist(7));
+    ///
+    /// let mut baz: DoubleOption<i32, i32> = Empty;
+    /// baz.swap_in_place();
+    /// assert_eq!(baz, Empty);
+    /// ```
+    #[inline]
+    pub fn swap_in_place(&mut self) {
+        *self = mem::take(self).swap();
+    }
+
+    /// Returns whichever payload is present, or [`None`] for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::path::PathBuf;
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<PathBuf, PathBuf> = Fist(PathBuf::from("/etc/app.toml"));
+    /// assert_eq!(foo.any(), Some(PathBuf::from("/etc/app.toml")));
+    ///
+    /// let bar: DoubleOption<PathBuf, PathBuf> = Second(PathBuf::from("~/.app.toml"));
+    /// assert_eq!(bar.any(), Some(PathBuf::from("~/.app.toml")));
+    ///
+    /// let baz: DoubleOption<PathBuf, PathBuf> = Empty;
+    /// assert_eq!(baz.any(), None);
+    /// ```
+    #[inline]
+    pub fn any(self) -> Option<T> {
+        match self {
+            Self::Fist(inner) | Self::Second(inner) => Some(inner),
+            Self::Empty => None
+        }
+    }
+
+    /// Returns a reference to whichever payload is present, or [`None`] for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<String, String> = Fist("first".to_string());
+    /// assert_eq!(foo.inner_ref().map(String::as_str), Some("first"));
+    ///
+    /// let bar: DoubleOption<String, String> = Second("second".to_string());
+    /// assert_eq!(bar.inner_ref().map(String::as_str), Some("second"));
+    ///
+    /// let baz: DoubleOption<String, String> = Empty;
+    /// assert_eq!(baz.inner_ref(), None);
+    /// ```
+    #[inline]
+    pub fn inner_ref(&self) -> Option<&T> {
+        match self {
+            Self::Fist(inner) | Self::Second(inner) => Some(inner),
+            Self::Empty => None
+        }
+    }
+
+    /// Maps whichever payload is present with `f`, keeping the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// let foo: DoubleOption<&str, &str> = Fist("first");
+    /// assert_eq!(foo.map_both(str::len), Fist(5));
+    ///
+    /// let bar: DoubleOption<&str, &str> = Second("second");
+    /// assert_eq!(bar.map_both(str::len), Second(6));
+    ///
+    /// let baz: DoubleOption<&str, &str> = Empty;
+    /// assert_eq!(baz.map_both(str::len), Empty);
+    /// ```
+    #[inline]
+    pub fn map_both<O, F>(self, f: F) -> DoubleOption<O, O>
+    where
+        F: FnOnce(T) -> O
+    {
+        match self {
+            Self::Fist(first) => DoubleOption::Fist(f(first)),
+            Self::Second(second) => DoubleOption::Second(f(second)),
+            Self::Empty => DoubleOption::Empty
+        }
+    }
+}
+
+#[cfg(feature = "str")]
+impl<T: FromStr, U: FromStr> DoubleOption<T, U> {
+    /// Parses `s` into a `DoubleOption`, see the [`FromStr`] implementation for the rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+    /// assert_eq!(DoubleOption::<u32, char>::parse_double_option("42"), Ok(Fist(42)));
+    /// assert_eq!(DoubleOption::<u32, char>::parse_double_option("x"), Ok(Second('x')));
+    /// assert_eq!(DoubleOption::<u32, char>::parse_double_option(""), Ok(Empty));
+    /// ```
+    #[inline]
+    pub fn parse_double_option(s: &str) -> Result<Self, ParseDoubleOptionError<T::Err, U::Err>> {
+        s.parse()
+    }
+}
+
+/// Returns `Empty`.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::doubleoption::DoubleOption::{self, Fist, Empty};
+/// #[derive(Default)]
+/// struct Slot {
+///     inner: DoubleOption<i32, String>
+/// }
+///
+/// let mut foo = Slot::default();
+/// assert_eq!(foo.inner, Empty);
+///
+/// foo.inner = Fist(42);
+/// assert_eq!(std::mem::take(&mut foo.inner), Fist(42));
+/// assert_eq!(foo.inner, Empty);
+///
+/// let mut bar = DoubleOption::default();
+/// bar.clone_from(&foo.inner);
+/// assert_eq!(bar, Empty);
+///
+/// bar.clone_from(&Fist(7));
+/// assert_eq!(bar, Fist(7));
+///
+/// bar.clone_from(&DoubleOption::default());
+/// assert_eq!(bar, Empty);
+/// ```
+impl<T, U> Default for DoubleOption<T, U> {
+    #[inline]
+    fn default() -> DoubleOption<T, U> {
+        Self::Empty
+    }
+}
+
 impl<T: Clone, U: Clone> Clone for DoubleOption<T, U> {
     fn clone(&self) -> Self {
         match self {
@@ -23,4 +3283,662 @@ impl<T: Clone, U: Clone> Clone for DoubleOption<T, U> {
             (destination, source) => *destination = source.clone()
         }
     }
-}
\ No newline at end of file
+}
+
+/// Converts an `Option<Double<T, U>>` into a `DoubleOption`, where [`None`] maps to `Empty`.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::doubleoption::DoubleOption::{self, Second};
+/// # use urt::double::Double::That;
+/// let foo: DoubleOption<i32, &str> = Some(That("second")).into();
+/// assert_eq!(foo, Second("second"));
+/// ```
+impl<T, U> From<Option<Double<T, U>>> for DoubleOption<T, U> {
+    #[inline]
+    fn from(option: Option<Double<T, U>>) -> Self {
+        Self::from_option_double(option)
+    }
+}
+
+/// Converts a `DoubleOption` into an `Option<Double<T, U>>`, where `Empty` maps to [`None`].
+///
+/// # Examples
+///
+/// ```
+/// # use urt::doubleoption::DoubleOption::{Fist, Empty};
+/// # use urt::double::Double::{self, This};
+/// let foo: Option<Double<i32, &str>> = Fist(42).into();
+/// assert_eq!(foo, Some(This(42)));
+///
+/// let bar: Option<Double<i32, &str>> = Empty.into();
+/// assert_eq!(bar, None);
+/// ```
+impl<T, U> From<DoubleOption<T, U>> for Option<Double<T, U>> {
+    #[inline]
+    fn from(double_option: DoubleOption<T, U>) -> Self {
+        double_option.into_option_double()
+    }
+}
+
+/// Error returned by [`DoubleOption::try_from_options`] if both [`Option`]s are [`Some`].
+///
+/// Both payloads are handed back so no data is lost.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BothPresent<T, U>(pub T, pub U);
+
+impl<T, U> Display for BothPresent<T, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("expected at most one `Some`, found two `Some`s")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug, U: Debug> Error for BothPresent<T, U> {}
+
+/// Parses a `DoubleOption` from a string.
+///
+/// Input which is empty after trimming whitespace yields `Empty`. Otherwise the trimmed
+/// input is parsed as `T`, giving `Fist`, and if that fails as `U`, giving `Second`.
+/// If neither parses, both errors are returned in a [`ParseDoubleOptionError`].
+///
+/// Input which parses as both `T` and `U` always becomes `Fist`.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::doubleoption::{ParseDoubleOptionError, DoubleOption::{self, Fist, Second, Empty}};
+/// // An optional field holding either a numeric id or a name.
+/// type Field = DoubleOption<u32, String>;
+///
+/// assert_eq!(" 42 ".parse::<Field>(), Ok(Fist(42)));
+/// assert_eq!("foo".parse::<Field>(), Ok(Second("foo".to_string())));
+/// assert_eq!("   ".parse::<Field>(), Ok(Empty));
+/// assert_eq!("".parse::<Field>(), Ok(Empty));
+///
+/// let error: ParseDoubleOptionError<_, _> = "x".parse::<DoubleOption<u32, bool>>().unwrap_err();
+/// assert_eq!(error.first, "x".parse::<u32>().unwrap_err());
+/// assert_eq!(error.second, "x".parse::<bool>().unwrap_err());
+/// assert_eq!(
+///     error.to_string(),
+///     "failed to parse either variant: invalid digit found in string; provided string was not `true` or `false`"
+/// );
+/// ```
+#[cfg(feature = "str")]
+impl<T: FromStr, U: FromStr> FromStr for DoubleOption<T, U> {
+    type Err = ParseDoubleOptionError<T::Err, U::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Self::Empty);
+        }
+
+        match s.parse() {
+            Ok(first) => Ok(Self::Fist(first)),
+            Err(first) => match s.parse() {
+                Ok(second) => Ok(Self::Second(second)),
+                Err(second) => Err(ParseDoubleOptionError { first, second })
+            }
+        }
+    }
+}
+
+/// Error returned when parsing a [`DoubleOption`] fails, holding the errors of both attempts.
+#[cfg(feature = "str")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ParseDoubleOptionError<E1, E2> {
+    /// The error from parsing the first type.
+    pub first: E1,
+    /// The error from parsing the second type.
+    pub second: E2
+}
+
+#[cfg(feature = "str")]
+impl<E1: Display, E2: Display> Display for ParseDoubleOptionError<E1, E2> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse either variant: {}; {}", self.first, self.second)
+    }
+}
+
+#[cfg(all(feature = "str", feature = "std"))]
+impl<E1: Error, E2: Error> Error for ParseDoubleOptionError<E1, E2> {}
+
+/// Serde helpers writing `DoubleOption` as a struct of two [`Option`]s.
+///
+/// Use this module via `#[serde(with = "urt::doubleoption::option_pair")]`.
+/// `Fist(x)` is written as `{"first": x, "second": null}`, `Second(y)` as
+/// `{"first": null, "second": y}` and `Empty` as `{"first": null, "second": null}`.
+///
+/// Deserializing goes through [`DoubleOption::try_from_options`], input where both
+/// fields are present is rejected. Missing fields in self-describing formats are read as
+/// `None`. Since the representation is a plain struct it also works with formats which
+/// are not self-describing.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::{Serialize, Deserialize};
+/// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Message {
+///     #[serde(with = "urt::doubleoption::option_pair")]
+///     payload: DoubleOption<u32, String>
+/// }
+///
+/// let foo = Message { payload: Fist(42) };
+/// let json = r#"{"payload":{"first":42,"second":null}}"#;
+/// assert_eq!(serde_json::to_string(&foo).unwrap(), json);
+/// assert_eq!(serde_json::from_str::<Message>(json).unwrap(), foo);
+///
+/// let bar = Message { payload: Second(String::from("second")) };
+/// let json = r#"{"payload":{"first":null,"second":"second"}}"#;
+/// assert_eq!(serde_json::to_string(&bar).unwrap(), json);
+/// assert_eq!(serde_json::from_str::<Message>(json).unwrap(), bar);
+///
+/// let baz = Message { payload: Empty };
+/// let json = r#"{"payload":{"first":null,"second":null}}"#;
+/// assert_eq!(serde_json::to_string(&baz).unwrap(), json);
+/// assert_eq!(serde_json::from_str::<Message>(json).unwrap(), baz);
+/// assert_eq!(serde_json::from_str::<Message>(r#"{"payload":{}}"#).unwrap(), baz);
+///
+/// // Both fields present is rejected.
+/// assert!(serde_json::from_str::<Message>(r#"{"payload":{"first":42,"second":"second"}}"#).is_err());
+///
+/// // Round-trips through a format which is not self-describing.
+/// for message in [foo, bar, baz] {
+///     let bytes = bincode::serialize(&message).unwrap();
+///     assert_eq!(bincode::deserialize::<Message>(&bytes).unwrap(), message);
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod option_pair {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::DoubleOption::{self, Fist, Second, Empty};
+
+    #[derive(Serialize)]
+    #[serde(rename = "DoubleOption")]
+    struct OptionPairRef<'a, T, U> {
+        first: Option<&'a T>,
+        second: Option<&'a U>
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "DoubleOption")]
+    struct OptionPair<T, U> {
+        first: Option<T>,
+        second: Option<U>
+    }
+
+    /// Serializes the `DoubleOption` as a struct with the fields `first` and `second`.
+    pub fn serialize<T, U, S>(double_option: &DoubleOption<T, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        U: Serialize,
+        S: Serializer
+    {
+        let pair = match double_option {
+            Fist(first) => OptionPairRef { first: Some(first), second: None },
+            Second(second) => OptionPairRef { first: None, second: Some(second) },
+            Empty => OptionPairRef { first: None, second: None }
+        };
+        pair.serialize(serializer)
+    }
+
+    /// Deserializes a struct with the fields `first` and `second`, failing if both are present.
+    pub fn deserialize<'de, T, U, D>(deserializer: D) -> Result<DoubleOption<T, U>, D::Error>
+    where
+        T: Deserialize<'de>,
+        U: Deserialize<'de>,
+        D: Deserializer<'de>
+    {
+        let pair = OptionPair::deserialize(deserializer)?;
+        DoubleOption::try_from_options((pair.first, pair.second)).map_err(de::Error::custom)
+    }
+}
+
+/// Serde helpers writing `DoubleOption` as its bare payload.
+///
+/// Use this module via `#[serde(with = "urt::doubleoption::untagged")]`.
+/// `Fist(x)` is written as `x`, `Second(y)` as `y` and `Empty` as `null`.
+///
+/// Since no tag is written, deserializing has to guess the variant:
+///
+/// 1. `null` is always read as `Empty`.
+/// 2. Otherwise the input is read as `T`, giving `Fist`.
+/// 3. If that fails the input is read as `U`, giving `Second`.
+///
+/// This makes the representation lossy whenever the input of one variant can be read as an
+/// earlier one. A `Second` whose payload also parses as `T` comes back as `Fist`, and a payload
+/// written as `null`, like `Fist(None)` for an `Option` payload, comes back as `Empty`.
+/// Guessing requires buffering the input, so deserializing only works with self-describing
+/// formats, formats like `bincode` return an error.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::{Serialize, Deserialize};
+/// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Message {
+///     #[serde(with = "urt::doubleoption::untagged")]
+///     payload: DoubleOption<u32, String>
+/// }
+///
+/// let foo = Message { payload: Fist(42) };
+/// let json = r#"{"payload":42}"#;
+/// assert_eq!(serde_json::to_string(&foo).unwrap(), json);
+/// assert_eq!(serde_json::from_str::<Message>(json).unwrap(), foo);
+///
+/// let bar = Message { payload: Second(String::from("second")) };
+/// let json = r#"{"payload":"second"}"#;
+/// assert_eq!(serde_json::to_string(&bar).unwrap(), json);
+/// assert_eq!(serde_json::from_str::<Message>(json).unwrap(), bar);
+///
+/// let baz = Message { payload: Empty };
+/// let json = r#"{"payload":null}"#;
+/// assert_eq!(serde_json::to_string(&baz).unwrap(), json);
+/// assert_eq!(serde_json::from_str::<Message>(json).unwrap(), baz);
+///
+/// // Neither payload matches.
+/// assert!(serde_json::from_str::<Message>(r#"{"payload":true}"#).is_err());
+///
+/// // Formats which are not self-describing can write, but not read the representation.
+/// let bytes = bincode::serialize(&foo).unwrap();
+/// assert!(bincode::deserialize::<Message>(&bytes).is_err());
+/// ```
+///
+/// The ambiguous cases:
+///
+/// ```
+/// # use serde::{Serialize, Deserialize};
+/// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Ambiguous {
+///     #[serde(with = "urt::doubleoption::untagged")]
+///     payload: DoubleOption<Option<u32>, u64>
+/// }
+///
+/// // `Second(7)` also parses as `Option<u32>`, so it comes back as `Fist`.
+/// let foo = Ambiguous { payload: Second(7) };
+/// let json = serde_json::to_string(&foo).unwrap();
+/// assert_eq!(serde_json::from_str::<Ambiguous>(&json).unwrap().payload, Fist(Some(7)));
+///
+/// // `Fist(None)` is written as `null`, so it comes back as `Empty`.
+/// let bar = Ambiguous { payload: Fist(None) };
+/// let json = serde_json::to_string(&bar).unwrap();
+/// assert_eq!(serde_json::from_str::<Ambiguous>(&json).unwrap().payload, Empty);
+/// ```
+#[cfg(feature = "serde")]
+pub mod untagged {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::DoubleOption::{self, Fist, Second, Empty};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Untagged<T, U> {
+        Empty,
+        Fist(T),
+        Second(U)
+    }
+
+    /// Serializes the payload of the `DoubleOption`, or `None` if it is `Empty`.
+    pub fn serialize<T, U, S>(double_option: &DoubleOption<T, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        U: Serialize,
+        S: Serializer
+    {
+        match double_option {
+            Fist(first) => first.serialize(serializer),
+            Second(second) => second.serialize(serializer),
+            Empty => serializer.serialize_none()
+        }
+    }
+
+    /// Deserializes `null` as `Empty`, then tries `T` and `U` in that order.
+    pub fn deserialize<'de, T, U, D>(deserializer: D) -> Result<DoubleOption<T, U>, D::Error>
+    where
+        T: Deserialize<'de>,
+        U: Deserialize<'de>,
+        D: Deserializer<'de>
+    {
+        match Untagged::deserialize(deserializer)? {
+            Untagged::Fist(first) => Ok(Fist(first)),
+            Untagged::Second(second) => Ok(Second(second)),
+            Untagged::Empty => Ok(Empty)
+        }
+    }
+}
+
+/// Extension trait splitting an iterator of [`DoubleOption`]s by variant.
+///
+/// Implemented for every iterator over `DoubleOption<T, U>`.
+pub trait PartitionDoubleOption<T, U>: Iterator<Item = DoubleOption<T, U>> {
+    /// Consumes the iterator, collecting the `Fist` payloads into the first container,
+    /// the `Second` payloads into the second container and counting the `Empty`s.
+    ///
+    /// Each container keeps the order in which its payloads were yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::doubleoption::{PartitionDoubleOption, DoubleOption::{self, Fist, Second, Empty}};
+    /// let matches: [DoubleOption<u32, &str>; 6] = [Fist(3), Empty, Second("bar"), Fist(1), Second("foo"), Empty];
+    ///
+    /// let (by_id, by_name, unmatched): (Vec<_>, Vec<_>, _) = matches.into_iter().partition_double_option();
+    /// assert_eq!(by_id, [3, 1]);
+    /// assert_eq!(by_name, ["bar", "foo"]);
+    /// assert_eq!(unmatched, 2);
+    /// ```
+    ///
+    /// Any container implementing [`Default`] and [`Extend`] works:
+    ///
+    /// ```
+    /// # use std::collections::BTreeSet;
+    /// # use urt::doubleoption::{PartitionDoubleOption, DoubleOption::{self, Fist, Second, Empty}};
+    /// let matches: [DoubleOption<char, u8>; 6] = [Fist('b'), Second(2), Empty, Fist('a'), Second(2), Fist('b')];
+    ///
+    /// let (chars, bytes, empties): (String, BTreeSet<u8>, _) = matches.into_iter().partition_double_option();
+    /// assert_eq!(chars, "bab");
+    /// assert_eq!(bytes, BTreeSet::from([2]));
+    /// assert_eq!(empties, 1);
+    /// ```
+    fn partition_double_option<C1, C2>(self) -> (C1, C2, usize)
+    where
+        Self: Sized,
+        C1: Default + Extend<T>,
+        C2: Default + Extend<U>
+    {
+        let mut firsts = C1::default();
+        let mut seconds = C2::default();
+        let mut empties = 0;
+
+        self.for_each(|double_option| match double_option {
+            DoubleOption::Fist(first) => firsts.extend(Some(first)),
+            DoubleOption::Second(second) => seconds.extend(Some(second)),
+            DoubleOption::Empty => empties += 1
+        });
+
+        (firsts, seconds, empties)
+    }
+}
+
+impl<T, U, I> PartitionDoubleOption<T, U> for I
+where
+    I: Iterator<Item = DoubleOption<T, U>>
+{}
+
+impl<T, U> IntoIterator for DoubleOption<T, U> {
+    type Item = Double<T, U>;
+    type IntoIter = IntoIter<T, U>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { inner: self.into_double() }
+    }
+}
+
+impl<'a, T, U> IntoIterator for &'a DoubleOption<T, U> {
+    type Item = Double<&'a T, &'a U>;
+    type IntoIter = Iter<'a, T, U>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, U> IntoIterator for &'a mut DoubleOption<T, U> {
+    type Item = Double<&'a mut T, &'a mut U>;
+    type IntoIter = IterMut<'a, T, U>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// The DoubleOption Iterators
+/////////////////////////////////////////////////////////////////////////////
+
+// Like the ErrorOption iterators both ends share the single `inner` slot.
+
+/// An iterator over references to the value of a [`DoubleOption`].
+///
+/// This `struct` is created by the [`DoubleOption::iter`] function.
+#[derive(Debug)]
+pub struct Iter<'a, T: 'a, U: 'a> {
+    inner: Option<Double<&'a T, &'a U>>
+}
+
+impl<'a, T, U> Iterator for Iter<'a, T, U> {
+    type Item = Double<&'a T, &'a U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, T, U> DoubleEndedIterator for Iter<'a, T, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+}
+
+impl<T, U> ExactSizeIterator for Iter<'_, T, U> {}
+
+impl<T, U> FusedIterator for Iter<'_, T, U> {}
+
+impl<T, U> Clone for Iter<'_, T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { inner: self.inner }
+    }
+}
+
+/// An iterator over mutable references to the value of a [`DoubleOption`].
+///
+/// This `struct` is created by the [`DoubleOption::iter_mut`] function.
+#[derive(Debug)]
+pub struct IterMut<'a, T: 'a, U: 'a> {
+    inner: Option<Double<&'a mut T, &'a mut U>>
+}
+
+impl<'a, T, U> Iterator for IterMut<'a, T, U> {
+    type Item = Double<&'a mut T, &'a mut U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, T, U> DoubleEndedIterator for IterMut<'a, T, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+}
+
+impl<T, U> ExactSizeIterator for IterMut<'_, T, U> {}
+
+impl<T, U> FusedIterator for IterMut<'_, T, U> {}
+
+/// An iterator over the value of a [`DoubleOption`].
+///
+/// This `struct` is created by the `into_iter` method on [`DoubleOption`].
+///
+/// # Examples
+///
+/// ```
+/// # use urt::doubleoption::DoubleOption::{self, Fist, Second, Empty};
+/// # use urt::double::Double::{This, That};
+/// let foo: Vec<DoubleOption<i32, &str>> = vec![Fist(1), Empty, Second("two"), Fist(3)];
+/// let bar: Vec<_> = foo.into_iter().flatten().collect();
+/// assert_eq!(bar, vec![This(1), That("two"), This(3)]);
+///
+/// let mut iter = Second::<i32, &str>("second").into_iter();
+/// assert_eq!(iter.len(), 1);
+/// assert_eq!(iter.next_back(), Some(That("second")));
+/// assert_eq!(iter.len(), 0);
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntoIter<T, U> {
+    inner: Option<Double<T, U>>
+}
+
+impl<T, U> Iterator for IntoIter<T, U> {
+    type Item = Double<T, U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<T, U> DoubleEndedIterator for IntoIter<T, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+}
+
+impl<T, U> ExactSizeIterator for IntoIter<T, U> {}
+
+impl<T, U> FusedIterator for IntoIter<T, U> {}
+
+/// An iterator over a reference to the value of a single channel of a [`DoubleOption`].
+///
+/// This `struct` is created by the [`DoubleOption::iter_first`] and
+/// [`DoubleOption::iter_second`] functions.
+#[derive(Debug)]
+pub struct ChannelIter<'a, A: 'a> {
+    inner: Option<&'a A>
+}
+
+impl<'a, A> Iterator for ChannelIter<'a, A> {
+    type Item = &'a A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, A> DoubleEndedIterator for ChannelIter<'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+}
+
+impl<A> ExactSizeIterator for ChannelIter<'_, A> {}
+
+impl<A> FusedIterator for ChannelIter<'_, A> {}
+
+impl<A> Clone for ChannelIter<'_, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        ChannelIter { inner: self.inner }
+    }
+}
+
+/// An iterator over a mutable reference to the value of a single channel of a [`DoubleOption`].
+///
+/// This `struct` is created by the [`DoubleOption::iter_first_mut`] and
+/// [`DoubleOption::iter_second_mut`] functions.
+#[derive(Debug)]
+pub struct ChannelIterMut<'a, A: 'a> {
+    inner: Option<&'a mut A>
+}
+
+impl<'a, A> Iterator for ChannelIterMut<'a, A> {
+    type Item = &'a mut A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, A> DoubleEndedIterator for ChannelIterMut<'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+}
+
+impl<A> ExactSizeIterator for ChannelIterMut<'_, A> {}
+
+impl<A> FusedIterator for ChannelIterMut<'_, A> {}
+
+/// An iterator over the value of a single channel of a [`DoubleOption`].
+///
+/// This `struct` is created by the [`DoubleOption::into_iter_first`] and
+/// [`DoubleOption::into_iter_second`] functions.
+#[derive(Clone, Debug)]
+pub struct ChannelIntoIter<A> {
+    inner: Option<A>
+}
+
+impl<A> Iterator for ChannelIntoIter<A> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<A> DoubleEndedIterator for ChannelIntoIter<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.take()
+    }
+}
+
+impl<A> ExactSizeIterator for ChannelIntoIter<A> {}
+
+impl<A> FusedIterator for ChannelIntoIter<A> {}
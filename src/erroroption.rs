@@ -4,16 +4,36 @@ extern crate std;
 #[cfg(feature = "serde")]
 extern crate serde;
 
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
 use core::{
     iter::FusedIterator,
-    ops::{Deref, DerefMut},
+    ops::{ControlFlow, Deref, DerefMut},
     default::Default,
     marker::Copy,
-    hint, mem, fmt::Debug  
+    hint, mem, ptr, fmt::{Debug, Display}
 };
 
+use crate::doubleoption::DoubleOption;
+
 use self::ErrorOption::{Value, Empty, Error};
 
+mod private {
+    use super::{Debug, Display};
+
+    /// Minimal `no_std` friendly stand-in for `std::error::Error`.
+    ///
+    /// Implemented for every type which is both [`Debug`] and [`Display`]. The trait
+    /// lives in a private module so it can be used in bounds without being nameable
+    /// outside the crate.
+    pub trait UrtError: Debug + Display {}
+
+    impl<E: Debug + Display + ?Sized> UrtError for E {}
+}
+
+use self::private::UrtError;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use = "this `ErrorOption` may be an `Error` variant, which should be handeled"]
 #[derive(Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
@@ -28,6 +48,53 @@ pub enum ErrorOption<T, E> {
 }
 
 impl<T, E> ErrorOption<T, E> {
+    /////////////////////////////////////////////////////////////////////////
+    // Constructors
+    /////////////////////////////////////////////////////////////////////////
+
+    /// Creates a `Value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption;
+    /// const FOO: ErrorOption<i32, &str> = ErrorOption::from_value(42);
+    /// assert_eq!(FOO, ErrorOption::Value(42));
+    /// ```
+    #[inline]
+    pub const fn from_value(value: T) -> Self {
+        Value(value)
+    }
+
+    /// Creates an `Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption;
+    /// const FOO: ErrorOption<i32, &str> = ErrorOption::from_error("error");
+    /// assert_eq!(FOO, ErrorOption::Error("error"));
+    /// ```
+    #[inline]
+    pub const fn from_error(error: E) -> Self {
+        Error(error)
+    }
+
+    /// Creates an `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption;
+    /// const FOO: ErrorOption<i32, &str> = ErrorOption::empty();
+    /// const _: () = assert!(FOO.is_empty());
+    /// assert_eq!(FOO, ErrorOption::Empty);
+    /// ```
+    #[inline]
+    pub const fn empty() -> Self {
+        Empty
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Querying the contained values
     /////////////////////////////////////////////////////////////////////////
@@ -144,6 +211,30 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Returns the name of the variant, without formatting the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Error, Empty};
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.variant_name(), "Value");
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.variant_name(), "Empty");
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("This is an error!");
+    /// assert_eq!(baz.variant_name(), "Error");
+    /// ```
+    #[inline]
+    pub const fn variant_name(&self) -> &'static str {
+        match *self {
+            Value(_) => "Value",
+            Empty => "Empty",
+            Error(_) => "Error"
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Adapter for each variant
     /////////////////////////////////////////////////////////////////////////
@@ -221,6 +312,56 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Returns the contained `Value`, or hands back the untouched `ErrorOption` as [`Err`].
+    ///
+    /// Unlike [`as_option`](ErrorOption::as_option), an `Error` is not dropped on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, String> = Value(42);
+    /// assert_eq!(foo.try_into_value(), Ok(42));
+    ///
+    /// let bar: ErrorOption<i32, String> = Empty;
+    /// assert_eq!(bar.try_into_value(), Err(Empty));
+    ///
+    /// let baz: ErrorOption<i32, String> = Error("error".to_string());
+    /// assert_eq!(baz.try_into_value(), Err(Error("error".to_string())));
+    /// ```
+    #[inline]
+    pub fn try_into_value(self) -> Result<T, Self> {
+        match self {
+            Value(value) => Ok(value),
+            other => Err(other)
+        }
+    }
+
+    /// Returns the contained `Error`, or hands back the untouched `ErrorOption` as [`Err`].
+    ///
+    /// Unlike [`error`](ErrorOption::error), a `Value` is not dropped on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<String, &str> = Error("error");
+    /// assert_eq!(foo.try_into_error(), Ok("error"));
+    ///
+    /// let bar: ErrorOption<String, &str> = Empty;
+    /// assert_eq!(bar.try_into_error(), Err(Empty));
+    ///
+    /// let baz: ErrorOption<String, &str> = Value("value".to_string());
+    /// assert_eq!(baz.try_into_error(), Err(Value("value".to_string())));
+    /// ```
+    #[inline]
+    pub fn try_into_error(self) -> Result<E, Self> {
+        match self {
+            Error(error) => Ok(error),
+            other => Err(other)
+        }
+    }
+
     /// Maps `ErrorOption` to [`Result`] using `err` as [`Err`].
     /// 
     /// # Examples
@@ -347,6 +488,79 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Maps `ErrorOption` to [`DoubleOption`], `Value` becomes `Fist`, `Error` becomes `Second`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// # use urt::doubleoption::DoubleOption;
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.into_double_option(), DoubleOption::Fist(42));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.into_double_option(), DoubleOption::Empty);
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("This is an error!");
+    /// assert_eq!(baz.into_double_option(), DoubleOption::Second("This is an error!"));
+    /// ```
+    #[inline]
+    pub fn into_double_option(self) -> DoubleOption<T, E> {
+        match self {
+            Value(value) => DoubleOption::Fist(value),
+            Empty => DoubleOption::Empty,
+            Error(error) => DoubleOption::Second(error)
+        }
+    }
+
+    /// Maps `ErrorOption` to [`ControlFlow`], where `Error` breaks and
+    /// `Value` and `Empty` continue with [`Some`] and [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.into_control_flow(), ControlFlow::Continue(Some(42)));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.into_control_flow(), ControlFlow::Continue(None));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("error");
+    /// assert_eq!(baz.into_control_flow(), ControlFlow::Break("error"));
+    ///
+    /// let parse = |s: &str| -> ErrorOption<i32, &str> {
+    ///     match s {
+    ///         "" => Empty,
+    ///         s => s.parse().map_or(Error("not a number"), Value)
+    ///     }
+    /// };
+    ///
+    /// let mut sum = 0;
+    /// let mut error = None;
+    /// for s in ["1", "", "2", "x", "3"] {
+    ///     match parse(s).into_control_flow() {
+    ///         ControlFlow::Continue(x) => sum += x.unwrap_or(0),
+    ///         ControlFlow::Break(e) => {
+    ///             error = Some(e);
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(error, Some("not a number"));
+    /// ```
+    #[inline]
+    pub fn into_control_flow(self) -> ControlFlow<E, Option<T>> {
+        match self {
+            Value(value) => ControlFlow::Continue(Some(value)),
+            Empty => ControlFlow::Continue(None),
+            Error(error) => ControlFlow::Break(error)
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Adapter for working with references
     /////////////////////////////////////////////////////////////////////////
@@ -405,6 +619,211 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Converts from `&mut ErrorOption<T, E>` to `Option<&mut T>`, where `Error` and `Empty` map to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let mut foo: ErrorOption<i32, &str> = Value(42);
+    /// if let Some(value) = foo.as_mut_option() {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(foo, Value(43));
+    ///
+    /// let mut bar: ErrorOption<i32, &str> = Error("error");
+    /// assert_eq!(bar.as_mut_option(), None);
+    ///
+    /// let mut baz: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(baz.as_mut_option(), None);
+    /// ```
+    #[inline]
+    pub fn as_mut_option(&mut self) -> Option<&mut T> {
+        match self {
+            Value(ref mut value) => Some(value),
+            _ => None
+        }
+    }
+
+    /// Converts from `&mut ErrorOption<T, E>` to `(Option<&mut T>, Option<&mut E>)`.
+    ///
+    /// At most one of the two is [`Some`], both are [`None`] for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let mut foo: ErrorOption<i32, String> = Value(42);
+    /// let (value, error) = foo.as_parts_mut();
+    /// assert!(error.is_none());
+    /// *value.unwrap() += 1;
+    /// assert_eq!(foo, Value(43));
+    ///
+    /// let mut bar: ErrorOption<i32, String> = Error("error".to_string());
+    /// let (value, error) = bar.as_parts_mut();
+    /// assert!(value.is_none());
+    /// error.unwrap().push_str(" with context");
+    /// assert_eq!(bar, Error("error with context".to_string()));
+    ///
+    /// let mut baz: ErrorOption<i32, String> = Empty;
+    /// assert_eq!(baz.as_parts_mut(), (None, None));
+    /// ```
+    #[inline]
+    pub fn as_parts_mut(&mut self) -> (Option<&mut T>, Option<&mut E>) {
+        match self {
+            Value(ref mut value) => (Some(value), None),
+            Empty => (None, None),
+            Error(ref mut error) => (None, Some(error))
+        }
+    }
+
+    /// Returns a raw pointer to the contained value, or a null pointer for `Empty` and `Error`.
+    ///
+    /// This lets foreign code read the value without knowing the layout of `ErrorOption`,
+    /// a null pointer means there is no value to read. The pointer is only valid as long
+    /// as `self` is neither moved nor modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// let ptr = foo.as_ptr();
+    /// assert!(!ptr.is_null());
+    /// assert_eq!(unsafe { *ptr }, 42);
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert!(bar.as_ptr().is_null());
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("This is an error!");
+    /// assert!(baz.as_ptr().is_null());
+    /// ```
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        match self {
+            Value(ref value) => value,
+            _ => ptr::null()
+        }
+    }
+
+    /// Returns a raw mutable pointer to the contained value, or a null pointer for `Empty` and `Error`.
+    ///
+    /// See [`as_ptr`](ErrorOption::as_ptr) for the null semantics. The pointer is only valid
+    /// as long as `self` is neither moved nor accessed through any other path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let mut foo: ErrorOption<i32, &str> = Value(42);
+    /// let ptr = foo.as_mut_ptr();
+    /// assert!(!ptr.is_null());
+    /// unsafe { *ptr += 1 };
+    /// assert_eq!(foo, Value(43));
+    ///
+    /// let mut bar: ErrorOption<i32, &str> = Empty;
+    /// assert!(bar.as_mut_ptr().is_null());
+    ///
+    /// let mut baz: ErrorOption<i32, &str> = Error("This is an error!");
+    /// assert!(baz.as_mut_ptr().is_null());
+    /// ```
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        match self {
+            Value(ref mut value) => value,
+            _ => ptr::null_mut()
+        }
+    }
+
+    /// Converts from `&ErrorOption<T, E>` to `Result<&T, &E>`, using `empty_err` for `Empty`.
+    ///
+    /// This is useful when a shared (e.g. `static`) error represents absence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// static MISSING: &str = "missing";
+    ///
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.as_result_ref_or(&MISSING), Ok(&42));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.as_result_ref_or(&MISSING), Err(&"missing"));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("This is an error!");
+    /// assert_eq!(baz.as_result_ref_or(&MISSING), Err(&"This is an error!"));
+    /// ```
+    #[inline]
+    pub fn as_result_ref_or<'a>(&'a self, empty_err: &'a E) -> Result<&'a T, &'a E> {
+        match self {
+            Value(ref value) => Ok(value),
+            Empty => Err(empty_err),
+            Error(ref error) => Err(error)
+        }
+    }
+
+    /// Converts from `&ErrorOption<T, E>` to `DoubleOption<&T, &E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// # use urt::doubleoption::DoubleOption;
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.as_double_option(), DoubleOption::Fist(&42));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.as_double_option(), DoubleOption::Empty);
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("This is an error!");
+    /// assert_eq!(baz.as_double_option(), DoubleOption::Second(&"This is an error!"));
+    /// ```
+    #[inline]
+    pub fn as_double_option(&self) -> DoubleOption<&T, &E> {
+        match self {
+            Value(ref value) => DoubleOption::Fist(value),
+            Empty => DoubleOption::Empty,
+            Error(ref error) => DoubleOption::Second(error)
+        }
+    }
+
+    /// Clones the `ErrorOption`, using `f` to produce the error from a reference.
+    ///
+    /// Only the value has to be [`Clone`], which allows cloning `ErrorOption`s whose
+    /// error type is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// #[derive(Debug)]
+    /// struct Unclonable(&'static str);
+    ///
+    /// let foo: ErrorOption<Vec<u8>, Unclonable> = Error(Unclonable("broken"));
+    /// let bar: ErrorOption<Vec<u8>, Arc<str>> = foo.clone_with(|e| Arc::from(e.0));
+    /// assert_eq!(bar, Error(Arc::from("broken")));
+    ///
+    /// let baz: ErrorOption<Vec<u8>, Unclonable> = Value(vec![1, 2, 3]);
+    /// assert!(matches!(baz.clone_with(|_| Unclonable("unused")), Value(v) if v == [1, 2, 3]));
+    ///
+    /// let qux: ErrorOption<Vec<u8>, Unclonable> = Empty;
+    /// assert!(qux.clone_with(|_| Unclonable("unused")).is_empty());
+    /// ```
+    #[inline]
+    pub fn clone_with<O, F>(&self, f: F) -> ErrorOption<T, O>
+    where
+        T: Clone,
+        F: FnOnce(&E) -> O
+    {
+        match self {
+            Value(ref value) => Value(value.clone()),
+            Empty => Empty,
+            Error(ref error) => Error(f(error))
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Getting to contained values
     /////////////////////////////////////////////////////////////////////////
@@ -425,6 +844,11 @@ impl<T, E> ErrorOption<T, E> {
     /// Panics if the value is an `Error` or `Empty`, with the passed message 
     /// and the error if present.
     ///
+    /// The error is formatted with [`Debug`], use [`expect_display`] to format it
+    /// with [`Display`] instead.
+    ///
+    /// [`expect_display`]: ErrorOption::expect_display
+    ///
     /// # Examples
     ///
     /// ```should_panic
@@ -438,6 +862,8 @@ impl<T, E> ErrorOption<T, E> {
     where
         E: Debug
     {
+        #[cfg(feature = "panic_immediate_abort")]
+        let _ = msg;
         match self {
             Value(value) => value,
             #[cfg(not(feature = "panic_immediate_abort"))]
@@ -446,41 +872,156 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
-    /// Returns the contained `Error`, consuming the `self` value.
-    /// 
-    /// Because this function may panic, its use is generally discouraged.
-    /// Instead, prefer to use pattern matching and handle the `Error` and `Empty`
-    /// variants explicitly.
+    /// Returns the contained `Value`, consuming the `self` value.
+    ///
+    /// Works like [`expect`], but formats the error with [`Display`] instead of [`Debug`].
+    /// Stable Rust has no specialization, so [`expect`] can not switch to `Display`
+    /// on its own whenever the error implements it; call this method instead.
+    ///
+    /// [`expect`]: ErrorOption::expect
     ///
     /// # Panics
     ///
-    /// Panics if the passed value is `Value` or `Empty`, with the passed message 
-    /// and value if present.
+    /// Panics if the value is an `Error` or `Empty`, with the passed message
+    /// and the displayed error if present.
     ///
     /// # Examples
     ///
-    /// ```should_panic
-    /// # use urt::erroroption::ErrorOption::{self, Value};
-    /// let foo: ErrorOption<i32, &str> = Value(42);
-    /// foo.expect_error("Testing expect"); // panics with `Testing expect: 42`
+    /// ```
+    /// # use std::fmt;
+    /// # use urt::erroroption::ErrorOption::{self, Empty, Error};
+    /// #[derive(Debug)]
+    /// struct Overheated { celsius: u32 }
+    ///
+    /// impl fmt::Display for Overheated {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "sensor reached {}°C", self.celsius)
+    ///     }
+    /// }
+    ///
+    /// let foo: ErrorOption<i32, Overheated> = Error(Overheated { celsius: 90 });
+    /// let panic = std::panic::catch_unwind(|| foo.expect_display("reading sensor")).unwrap_err();
+    /// # // `panic_immediate_abort` panics without a message.
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "reading sensor: sensor reached 90°C");
+    /// # }
+    ///
+    /// let bar: ErrorOption<i32, Overheated> = Empty;
+    /// let panic = std::panic::catch_unwind(|| bar.expect_display("reading sensor")).unwrap_err();
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "reading sensor");
+    /// # }
     /// ```
     #[inline]
     #[track_caller]
-    pub fn expect_error(self, msg: &str) -> E 
+    pub fn expect_display(self, msg: &str) -> T
     where
-        T: Debug
+        E: UrtError
     {
+        #[cfg(feature = "panic_immediate_abort")]
+        let _ = msg;
         match self {
-            Error(error) =>  error,
+            Value(value) => value,
             #[cfg(not(feature = "panic_immediate_abort"))]
-            Value(error) => panic!("{msg}: {error:?}"),
+            Error(error) => panic!("{msg}: {error}"),
+            #[cfg(not(feature = "panic_immediate_abort"))]
+            Empty => panic!("{msg}"),
+            #[cfg(feature = "panic_immediate_abort")]
             _ => panic!()
         }
     }
 
     /// Returns the contained `Value`, consuming the `self` value.
     ///
-    /// Because this function may panic, its use is generally discouraged.
+    /// The panic message is computed lazily by `f` from the error, which is
+    /// [`None`] for `Empty`, so detailed diagnostics are only built when needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an `Error` or `Empty`, with the message returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value};
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.expect_value_with(|_| unreachable!()), 42);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use urt::erroroption::ErrorOption::{self, Error};
+    /// let foo: ErrorOption<i32, &str> = Error("disk full");
+    /// foo.expect_value_with(|e| format!("writing 3 blocks: {}", e.unwrap())); // panics with `writing 3 blocks: disk full`
+    /// ```
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Empty};
+    /// let foo: ErrorOption<i32, &str> = Empty;
+    /// let panic = std::panic::catch_unwind(|| {
+    ///     foo.expect_value_with(|e| format!("reading config: {}", e.unwrap_or(&"nothing there")))
+    /// }).unwrap_err();
+    /// # // `panic_immediate_abort` panics without a message.
+    /// # if cfg!(not(feature = "panic_immediate_abort")) {
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "reading config: nothing there");
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[track_caller]
+    pub fn expect_value_with<F>(self, f: F) -> T
+    where
+        F: FnOnce(Option<&E>) -> String
+    {
+        #[cfg(feature = "panic_immediate_abort")]
+        let _ = f;
+        match self {
+            Value(value) => value,
+            #[cfg(not(feature = "panic_immediate_abort"))]
+            Empty => panic!("{}", f(None)),
+            #[cfg(not(feature = "panic_immediate_abort"))]
+            Error(ref error) => panic!("{}", f(Some(error))),
+            #[cfg(feature = "panic_immediate_abort")]
+            _ => panic!()
+        }
+    }
+
+    /// Returns the contained `Error`, consuming the `self` value.
+    /// 
+    /// Because this function may panic, its use is generally discouraged.
+    /// Instead, prefer to use pattern matching and handle the `Error` and `Empty`
+    /// variants explicitly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the passed value is `Value` or `Empty`, with the passed message 
+    /// and value if present.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use urt::erroroption::ErrorOption::{self, Value};
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// foo.expect_error("Testing expect"); // panics with `Testing expect: 42`
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_error(self, msg: &str) -> E 
+    where
+        T: Debug
+    {
+        #[cfg(feature = "panic_immediate_abort")]
+        let _ = msg;
+        match self {
+            Error(error) =>  error,
+            #[cfg(not(feature = "panic_immediate_abort"))]
+            Value(error) => panic!("{msg}: {error:?}"),
+            _ => panic!()
+        }
+    }
+
+    /// Returns the contained `Value`, consuming the `self` value.
+    ///
+    /// Because this function may panic, its use is generally discouraged.
     /// Instead, prefer to use pattern matching and handle the `Error` and `Empty`
     /// variants explicitly, or call [`unwrap_or`], [`unwrap_or_else`], or
     /// [`unwrap_or_default`].
@@ -596,6 +1137,89 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Returns the contained `Value`, or a clone of `default` if `self` is `Error` or `Empty`.
+    ///
+    /// `default` is only cloned if it is actually used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::Cell;
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// thread_local!(static CLONES: Cell<u32> = Cell::new(0));
+    ///
+    /// #[derive(PartialEq, Debug)]
+    /// struct Counted(i32);
+    ///
+    /// impl Clone for Counted {
+    ///     fn clone(&self) -> Self {
+    ///         CLONES.with(|clones| clones.set(clones.get() + 1));
+    ///         Counted(self.0)
+    ///     }
+    /// }
+    ///
+    /// let default = Counted(0);
+    ///
+    /// let foo: ErrorOption<Counted, &str> = Value(Counted(42));
+    /// assert_eq!(foo.unwrap_or_clone(&default), Counted(42));
+    /// assert_eq!(CLONES.with(Cell::get), 0);
+    ///
+    /// let bar: ErrorOption<Counted, &str> = Empty;
+    /// assert_eq!(bar.unwrap_or_clone(&default), Counted(0));
+    /// assert_eq!(CLONES.with(Cell::get), 1);
+    ///
+    /// let baz: ErrorOption<Counted, &str> = Error("This gets discarded!");
+    /// assert_eq!(baz.unwrap_or_clone(&default), Counted(0));
+    /// assert_eq!(CLONES.with(Cell::get), 2);
+    /// ```
+    #[inline]
+    pub fn unwrap_or_clone(self, default: &T) -> T
+    where
+        T: Clone
+    {
+        match self {
+            Value(value) => value,
+            _ => default.clone()
+        }
+    }
+
+    /// Converts the contained `Value` or `Error` into `O`, returning `O`'s default for `Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<u8, u16> = Value(42);
+    /// assert_eq!(foo.unwrap_or_into::<u32>(), 42);
+    ///
+    /// let bar: ErrorOption<u8, u16> = Empty;
+    /// assert_eq!(bar.unwrap_or_into::<u32>(), 0);
+    ///
+    /// let baz: ErrorOption<u8, u16> = Error(500);
+    /// assert_eq!(baz.unwrap_or_into::<u32>(), 500);
+    /// ```
+    #[inline]
+    pub fn unwrap_or_into<O>(self) -> O
+    where
+        T: Into<O>,
+        E: Into<O>,
+        O: Default
+    {
+        match self {
+            Value(value) => value.into(),
+            Empty => O::default(),
+            Error(error) => error.into()
+        }
+    }
+
+    /// Returns the contained `Value`, consuming the `self` value, without checking
+    /// that the value is not `Error` or `Empty`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on `Error` or `Empty` is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     #[inline]
     #[track_caller]
     pub unsafe fn unwrap_unchecked(self) -> T {
@@ -622,8 +1246,38 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Works like [`map`](ErrorOption::map), but borrows `self` and copies the contained
+    /// value out instead of moving the whole `ErrorOption` through the match.
+    ///
+    /// Meant for hot loops over small `Copy` payloads such as `ErrorOption<u64, u32>`,
+    /// see `benches/map.rs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let items: [ErrorOption<u64, u32>; 3] = [Value(2), Empty, Error(7)];
+    ///
+    /// for item in items {
+    ///     assert_eq!(item.map_copied(|x| x * 3), item.map(|x| x * 3));
+    /// }
+    /// ```
     #[inline]
-    pub fn map_or<M, F>(self, default: M, f: F) -> M 
+    pub fn map_copied<M, F>(&self, f: F) -> ErrorOption<M, E>
+    where
+        T: Copy,
+        E: Copy,
+        F: FnOnce(T) -> M
+    {
+        match *self {
+            Value(value) => Value(f(value)),
+            Empty => Empty,
+            Error(error) => Error(error)
+        }
+    }
+
+    #[inline]
+    pub fn map_or<M, F>(self, default: M, f: F) -> M
     where
         F: FnOnce(T) -> M
     {
@@ -645,6 +1299,26 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Reduces the `ErrorOption` to a single value by applying the function matching the variant.
+    ///
+    /// The closures are taken in the order `default_error`, `default`, `f`, that is
+    /// `Error`, `Empty` and `Value`. This mirrors [`Option::map_or_else`], where the
+    /// fallbacks come before the mapping function, with the `Error` fallback first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let describe = |x: ErrorOption<i32, &str>| x.map_or_error(
+    ///     |e| format!("error: {e}"),
+    ///     || "empty".to_string(),
+    ///     |v| format!("value: {v}")
+    /// );
+    ///
+    /// assert_eq!(describe(Value(42)), "value: 42");
+    /// assert_eq!(describe(Empty), "empty");
+    /// assert_eq!(describe(Error("broken")), "error: broken");
+    /// ```
     #[inline]
     pub fn map_or_error<M, DE, D, F>(self, default_error: DE, default: D, f: F) -> M
     where
@@ -659,6 +1333,39 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Works like [`map_or_error`](ErrorOption::map_or_error), but borrows `self`
+    /// and passes references to the closures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<String, String> = Value("value".to_string());
+    /// assert_eq!(foo.map_ref_or_error(|e| e.len() + 100, || 0, String::len), 5);
+    ///
+    /// let bar: ErrorOption<String, String> = Empty;
+    /// assert_eq!(bar.map_ref_or_error(|e| e.len() + 100, || 0, String::len), 0);
+    ///
+    /// let baz: ErrorOption<String, String> = Error("error".to_string());
+    /// assert_eq!(baz.map_ref_or_error(|e| e.len() + 100, || 0, String::len), 105);
+    ///
+    /// // `foo` is still usable.
+    /// assert_eq!(foo, Value("value".to_string()));
+    /// ```
+    #[inline]
+    pub fn map_ref_or_error<M, DE, D, F>(&self, default_error: DE, default: D, f: F) -> M
+    where
+        DE: FnOnce(&E) -> M,
+        D: FnOnce() -> M,
+        F: FnOnce(&T) -> M
+    {
+        match *self {
+            Value(ref value) => f(value),
+            Empty => default(),
+            Error(ref error) => default_error(error)
+        }
+    }
+
     #[inline]
     pub fn map_error<F, O>(self, f: F) -> ErrorOption<T, O>
     where
@@ -671,6 +1378,181 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Works like [`map_error`](ErrorOption::map_error), but borrows `self` and copies
+    /// the contained value out, see [`map_copied`](ErrorOption::map_copied).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let items: [ErrorOption<u64, u32>; 3] = [Value(2), Empty, Error(7)];
+    ///
+    /// for item in items {
+    ///     assert_eq!(item.map_error_copied(u64::from), item.map_error(u64::from));
+    /// }
+    /// ```
+    #[inline]
+    pub fn map_error_copied<F, O>(&self, f: F) -> ErrorOption<T, O>
+    where
+        T: Copy,
+        E: Copy,
+        F: FnOnce(E) -> O
+    {
+        match *self {
+            Value(value) => Value(value),
+            Empty => Empty,
+            Error(error) => Error(f(error))
+        }
+    }
+
+    /// Erases the error into a boxed [`Error`](std::error::Error) trait object,
+    /// leaving `Value` and `Empty` untouched.
+    ///
+    /// This allows storing `ErrorOption`s with different error types together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, std::fmt::Error> = Value(42);
+    /// let bar: ErrorOption<i32, std::io::Error> = Error(std::io::ErrorKind::NotFound.into());
+    /// let baz: ErrorOption<i32, std::num::ParseIntError> = Empty;
+    ///
+    /// let all = [foo.box_error(), bar.box_error(), baz.box_error()];
+    ///
+    /// assert!(matches!(all[0], Value(42)));
+    /// assert!(matches!(&all[1], Error(error) if error.is::<std::io::Error>()));
+    /// assert!(all[2].is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn box_error(self) -> ErrorOption<T, Box<dyn std::error::Error + Send + Sync>>
+    where
+        E: std::error::Error + Send + Sync + 'static
+    {
+        match self {
+            Value(value) => Value(value),
+            Empty => Empty,
+            Error(error) => Error(Box::new(error))
+        }
+    }
+
+    /// Maps an `Error` with `f` and promotes `Empty` to `Error(O::default())`.
+    ///
+    /// Afterwards every non-`Value` carries an error, `Empty` is never returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.map_error_or_default(str::len), Value(42));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Error("error");
+    /// assert_eq!(bar.map_error_or_default(str::len), Error(5));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(baz.map_error_or_default(str::len), Error(0));
+    /// ```
+    #[inline]
+    pub fn map_error_or_default<O, F>(self, f: F) -> ErrorOption<T, O>
+    where
+        O: Default,
+        F: FnOnce(E) -> O
+    {
+        match self {
+            Value(value) => Value(value),
+            Empty => Error(O::default()),
+            Error(error) => Error(f(error))
+        }
+    }
+
+    /// Returns the provided default for `Value` and `Empty`, otherwise applies `f` to the `Error`.
+    ///
+    /// This is the error side counterpart of [`map_or`](ErrorOption::map_or).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, &str> = Error("error");
+    /// assert_eq!(foo.error_map_or(0, str::len), 5);
+    ///
+    /// let bar: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(bar.error_map_or(0, str::len), 0);
+    ///
+    /// let baz: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(baz.error_map_or(0, str::len), 0);
+    /// ```
+    #[inline]
+    pub fn error_map_or<O, F>(self, default: O, f: F) -> O
+    where
+        F: FnOnce(E) -> O
+    {
+        match self {
+            Error(error) => f(error),
+            _ => default
+        }
+    }
+
+    /// Computes a default with `default` for `Value` and `Empty`, otherwise applies `f` to the `Error`.
+    ///
+    /// This is the error side counterpart of [`map_or_else`](ErrorOption::map_or_else).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, &str> = Error("error");
+    /// assert_eq!(foo.error_map_or_else(|| 0, str::len), 5);
+    ///
+    /// let bar: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(bar.error_map_or_else(|| 0, str::len), 0);
+    ///
+    /// let baz: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(baz.error_map_or_else(|| 0, str::len), 0);
+    /// ```
+    #[inline]
+    pub fn error_map_or_else<O, D, F>(self, default: D, f: F) -> O
+    where
+        D: FnOnce() -> O,
+        F: FnOnce(E) -> O
+    {
+        match self {
+            Error(error) => f(error),
+            _ => default()
+        }
+    }
+
+    /// Attaches context to an `Error`, leaving `Value` and `Empty` untouched.
+    ///
+    /// The context is only computed if `self` is `Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, &str> = Error("file not found");
+    /// assert_eq!(foo.context(|| "loading config"), Error(("loading config", "file not found")));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(bar.context(|| "loading config"), Value(42));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(baz.context(|| "loading config"), Empty);
+    /// ```
+    #[inline]
+    pub fn context<C, F>(self, f: F) -> ErrorOption<T, (C, E)>
+    where
+        F: FnOnce() -> C
+    {
+        match self {
+            Value(value) => Value(value),
+            Empty => Empty,
+            Error(error) => Error((f(), error))
+        }
+    }
+
     #[inline]
     pub fn inspect<F>(self, f: F) -> Self 
     where
@@ -695,6 +1577,66 @@ impl<T, E> ErrorOption<T, E> {
         self
     }
 
+    /// Calls a function with a reference to the contained `Value`, if any.
+    ///
+    /// Works like [`inspect`](ErrorOption::inspect), but borrows `self` instead of moving it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Error};
+    /// let mut seen = Vec::new();
+    ///
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert!(foo.inspect_ref(|x| seen.push(*x)).is_value());
+    ///
+    /// let bar: ErrorOption<i32, &str> = Error("error");
+    /// assert!(!bar.inspect_ref(|x| seen.push(*x)).is_value());
+    ///
+    /// assert_eq!(seen, [42]);
+    /// assert_eq!(foo, Value(42));
+    /// ```
+    #[inline]
+    pub fn inspect_ref<F>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&T)
+    {
+        if let Value(ref value) = *self {
+            f(value)
+        }
+
+        self
+    }
+
+    /// Gives the closure read-only access to the whole `ErrorOption`.
+    ///
+    /// Unlike [`inspect`](ErrorOption::inspect) this borrows `self` and returns nothing,
+    /// so it can be used to observe a value without threading it through a chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let mut log = Vec::new();
+    ///
+    /// let foo: ErrorOption<i32, &str> = Error("error");
+    /// foo.peek(|x| log.push(match x {
+    ///     Value(_) => "Value",
+    ///     Empty => "Empty",
+    ///     Error(_) => "Error"
+    /// }));
+    ///
+    /// assert_eq!(log, ["Error"]);
+    /// assert_eq!(foo, Error("error"));
+    /// ```
+    #[inline]
+    pub fn peek<F>(&self, f: F)
+    where
+        F: FnOnce(&Self)
+    {
+        f(self)
+    }
+
     #[inline]
     pub fn as_deref(&self) -> ErrorOption<&T::Target, &E>
     where
@@ -719,11 +1661,105 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Converts from `&ErrorOption<T, E>` to `ErrorOption<&T, &E::Target>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, String> = Error(String::from("This is an error!"));
+    /// assert_eq!(foo.as_deref_error(), Error("This is an error!"));
+    ///
+    /// let bar: ErrorOption<i32, String> = Value(42);
+    /// assert_eq!(bar.as_deref_error(), Value(&42));
+    ///
+    /// let baz: ErrorOption<i32, String> = Empty;
+    /// assert_eq!(baz.as_deref_error(), Empty);
+    /// ```
+    #[inline]
+    pub fn as_deref_error(&self) -> ErrorOption<&T, &E::Target>
+    where
+        E: Deref
+    {
+        match self {
+            Value(ref value) => Value(value),
+            Empty => Empty,
+            Error(ref error) => Error(error.deref())
+        }
+    }
+
+    /// Converts from `&mut ErrorOption<T, E>` to `ErrorOption<&mut T, &mut E::Target>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Error};
+    /// let mut foo: ErrorOption<i32, String> = Error(String::from("error"));
+    /// if let Error(error) = foo.as_deref_error_mut() {
+    ///     error.make_ascii_uppercase();
+    /// }
+    /// assert_eq!(foo, Error(String::from("ERROR")));
+    /// ```
+    #[inline]
+    pub fn as_deref_error_mut(&mut self) -> ErrorOption<&mut T, &mut E::Target>
+    where
+        E: DerefMut
+    {
+        match self {
+            Value(ref mut value) => Value(value),
+            Empty => Empty,
+            Error(ref mut error) => Error(error.deref_mut())
+        }
+    }
+
+    /// Returns an iterator over the possibly contained value.
+    ///
+    /// The iterator yields at most one element, no matter from which end it is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.iter().rev().collect::<Vec<_>>(), vec![&42]);
+    ///
+    /// let mut iter = foo.iter();
+    /// assert_eq!(iter.next(), Some(&42));
+    /// assert_eq!(iter.next_back(), None);
+    ///
+    /// let mut iter = foo.iter();
+    /// assert_eq!(iter.next_back(), Some(&42));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.iter().next_back(), None);
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("This is an error!");
+    /// assert_eq!(baz.iter().next_back(), None);
+    /// ```
     #[inline]
     pub fn iter(&self) -> Iter<'_, T> {
         Iter { inner: self.as_ref().as_option() }
     }
 
+    /// Returns a mutable iterator over the possibly contained value.
+    ///
+    /// The iterator yields at most one element, no matter from which end it is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value};
+    /// let mut foo: ErrorOption<i32, &str> = Value(42);
+    /// for value in foo.iter_mut().rev() {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(foo, Value(43));
+    ///
+    /// let mut iter = foo.iter_mut();
+    /// assert_eq!(iter.next(), Some(&mut 43));
+    /// assert_eq!(iter.next_back(), None);
+    /// ```
     #[inline]
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut { inner: self.as_mut().as_option() }
@@ -741,15 +1777,97 @@ impl<T, E> ErrorOption<T, E> {
     }
 
     #[inline]
-    pub fn and_then<M, F>(self, f: F) -> ErrorOption<M, E> 
+    pub fn and_then<M, F>(self, f: F) -> ErrorOption<M, E> 
+    where
+        F: FnOnce(T) -> ErrorOption<M, E>
+    {
+        match self {
+            Value(value) => f(value),
+            Empty => Empty,
+            Error(error) => Error(error)
+        }
+    }
+
+    /// Works like [`and_then`](ErrorOption::and_then), but promotes `Empty` to `Error(empty_error)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let half = |x: i32| if x % 2 == 0 { Value(x / 2) } else { Empty };
+    ///
+    /// let foo: ErrorOption<i32, &str> = Value(8);
+    /// assert_eq!(foo.and_then_or(half, "missing").and_then_or(half, "missing"), Value(2));
+    ///
+    /// // `half(3)` returns `Empty`, which is promoted by the next step of the chain.
+    /// let bar: ErrorOption<i32, &str> = Value(6);
+    /// let bar = bar.and_then_or(half, "missing").and_then_or(half, "missing");
+    /// assert_eq!(bar, Empty);
+    /// assert_eq!(bar.and_then_or(half, "missing"), Error("missing"));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(baz.and_then_or(half, "missing"), Error("missing"));
+    ///
+    /// let qux: ErrorOption<i32, &str> = Error("error");
+    /// assert_eq!(qux.and_then_or(half, "missing"), Error("error"));
+    /// ```
+    #[inline]
+    pub fn and_then_or<M, F>(self, f: F, empty_error: E) -> ErrorOption<M, E>
+    where
+        F: FnOnce(T) -> ErrorOption<M, E>
+    {
+        match self {
+            Value(value) => f(value),
+            Empty => Error(empty_error),
+            Error(error) => Error(error)
+        }
+    }
+
+    /// Applies a function wrapped in an `ErrorOption` to the value of `self`.
+    ///
+    /// The function is only called if both are `Value`. Errors take priority over
+    /// `Empty` and the error of `self` over the one of `f`, the same way as in
+    /// [`zip_to_error`](ErrorOption::zip_to_error).
+    ///
+    /// | `self`     | `f`        | result         |
+    /// |------------|------------|----------------|
+    /// | `Value(a)` | `Value(g)` | `Value(g(a))`  |
+    /// | `Value(a)` | `Empty`    | `Empty`        |
+    /// | `Value(a)` | `Error(y)` | `Error(y)`     |
+    /// | `Empty`    | `Value(g)` | `Empty`        |
+    /// | `Empty`    | `Empty`    | `Empty`        |
+    /// | `Empty`    | `Error(y)` | `Error(y)`     |
+    /// | `Error(x)` | any        | `Error(x)`     |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let double: ErrorOption<fn(i32) -> i32, &str> = Value(|x| x * 2);
+    /// let missing: ErrorOption<fn(i32) -> i32, &str> = Empty;
+    /// let broken: ErrorOption<fn(i32) -> i32, &str> = Error("no function");
+    ///
+    /// let foo: ErrorOption<i32, &str> = Value(21);
+    /// assert_eq!(foo.apply(double), Value(42));
+    /// assert_eq!(foo.apply(missing), Empty);
+    /// assert_eq!(foo.apply(broken), Error("no function"));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.apply(double), Empty);
+    /// assert_eq!(bar.apply(missing), Empty);
+    /// assert_eq!(bar.apply(broken), Error("no function"));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("no value");
+    /// assert_eq!(baz.apply(double), Error("no value"));
+    /// assert_eq!(baz.apply(missing), Error("no value"));
+    /// assert_eq!(baz.apply(broken), Error("no value"));
+    /// ```
+    #[inline]
+    pub fn apply<O, F>(self, f: ErrorOption<F, E>) -> ErrorOption<O, E>
     where
-        F: FnOnce(T) -> ErrorOption<M, E>
+        F: FnOnce(T) -> O
     {
-        match self {
-            Value(value) => f(value),
-            Empty => Empty,
-            Error(error) => Error(error)
-        }
+        self.zip_to_error(f).map(|(value, f)| f(value))
     }
 
     #[inline]
@@ -825,6 +1943,35 @@ impl<T, E> ErrorOption<T, E> {
         default(&self)
     }
 
+    /// Downgrades an `Error` matching `predicate` to `Empty`, keeping every other value as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let not_found = |error: &&str| *error == "not found";
+    ///
+    /// let foo: ErrorOption<i32, &str> = Error("not found");
+    /// assert_eq!(foo.suppress_error_if(not_found), Empty);
+    ///
+    /// let bar: ErrorOption<i32, &str> = Error("permission denied");
+    /// assert_eq!(bar.suppress_error_if(not_found), Error("permission denied"));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(baz.suppress_error_if(not_found), Value(42));
+    /// ```
+    #[doc(alias = "filter_error")]
+    #[inline]
+    pub fn suppress_error_if<P>(self, predicate: P) -> Self
+    where
+        P: FnOnce(&E) -> bool
+    {
+        match self {
+            Error(error) if predicate(&error) => Empty,
+            other => other
+        }
+    }
+
     #[inline]
     pub fn or(self, optb: Self) -> Self {
         match self {
@@ -844,6 +1991,41 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Returns `self` if it is a `Value`, otherwise calls `f` with the failure and returns the result.
+    ///
+    /// Unlike [`or_else`](ErrorOption::or_else) the closure can tell `Empty` and `Error` apart
+    /// and may change the error type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::{ErrorOptionFailure, ErrorOption::{self, Value, Empty, Error}};
+    /// let recover = |failure| match failure {
+    ///     ErrorOptionFailure::Empty => Value(0),
+    ///     ErrorOptionFailure::Error(e) => Error(format!("recovering failed: {e}"))
+    /// };
+    ///
+    /// let foo: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(foo.or_else_full(recover), Value(42));
+    ///
+    /// let bar: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(bar.or_else_full(recover), Value(0));
+    ///
+    /// let baz: ErrorOption<i32, &str> = Error("broken");
+    /// assert_eq!(baz.or_else_full(recover), Error("recovering failed: broken".to_string()));
+    /// ```
+    #[inline]
+    pub fn or_else_full<O, F>(self, f: F) -> ErrorOption<T, O>
+    where
+        F: FnOnce(ErrorOptionFailure<E>) -> ErrorOption<T, O>
+    {
+        match self {
+            Value(value) => Value(value),
+            Empty => f(ErrorOptionFailure::Empty),
+            Error(error) => f(ErrorOptionFailure::Error(error))
+        }
+    }
+
     #[inline]
     pub fn xor(self, optb: Self) -> Self {
         match (self, optb) {
@@ -853,12 +2035,99 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Merges two `ErrorOption`s, combining two `Value`s with `on_both_values`.
+    ///
+    /// The variants are ranked `Value` over `Error` over `Empty` and the higher ranked
+    /// operand wins. On a tie, two `Value`s are combined with `on_both_values` and of two
+    /// `Error`s the first one is kept. `Empty` is the identity of the merge.
+    ///
+    /// | `self`     | `other`    | result                        |
+    /// |------------|------------|-------------------------------|
+    /// | `Value(a)` | `Value(b)` | `Value(on_both_values(a, b))` |
+    /// | `Value(a)` | `Empty`    | `Value(a)`                    |
+    /// | `Value(a)` | `Error(y)` | `Value(a)`                    |
+    /// | `Empty`    | `Value(b)` | `Value(b)`                    |
+    /// | `Empty`    | `Empty`    | `Empty`                       |
+    /// | `Empty`    | `Error(y)` | `Error(y)`                    |
+    /// | `Error(x)` | `Value(b)` | `Value(b)`                    |
+    /// | `Error(x)` | `Empty`    | `Error(x)`                    |
+    /// | `Error(x)` | `Error(y)` | `Error(x)`                    |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let value: ErrorOption<i32, &str> = Value(1);
+    /// let empty: ErrorOption<i32, &str> = Empty;
+    /// let x: ErrorOption<i32, &str> = Error("x");
+    /// let add = |a, b| a + b;
+    ///
+    /// assert_eq!(value.combine(Value(2), add), Value(3));
+    /// assert_eq!(value.combine(Empty, add), Value(1));
+    /// assert_eq!(value.combine(Error("y"), add), Value(1));
+    /// assert_eq!(empty.combine(Value(2), add), Value(2));
+    /// assert_eq!(empty.combine(Empty, add), Empty);
+    /// assert_eq!(empty.combine(Error("y"), add), Error("y"));
+    /// assert_eq!(x.combine(Value(2), add), Value(2));
+    /// assert_eq!(x.combine(Empty, add), Error("x"));
+    /// assert_eq!(x.combine(Error("y"), add), Error("x"));
+    /// ```
+    ///
+    /// Folding a sequence:
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let results: [ErrorOption<i32, &str>; 4] = [Empty, Error("first"), Value(2), Value(3)];
+    /// let merged = results.into_iter().fold(Empty, |acc, next| acc.combine(next, i32::max));
+    /// assert_eq!(merged, Value(3));
+    /// ```
+    #[inline]
+    pub fn combine<F>(self, other: Self, on_both_values: F) -> Self
+    where
+        F: FnOnce(T, T) -> T
+    {
+        match (self, other) {
+            (Value(a), Value(b)) => Value(on_both_values(a, b)),
+            (Value(value), _) | (_, Value(value)) => Value(value),
+            (Error(error), _) | (_, Error(error)) => Error(error),
+            (Empty, Empty) => Empty
+        }
+    }
+
     #[must_use = "if you intended to set a value, consider assignment instead"]
     #[inline]
     pub fn insert(&mut self, value: T) -> &mut T {
         *self = Value(value);
         unsafe { self.as_mut().unwrap_unchecked() }
-    } 
+    }
+
+    /// Inserts `value` only if `self` is `Empty`, returning a mutable reference to it.
+    ///
+    /// If a `Value` or `Error` already occupies the slot it is left untouched and
+    /// `value` is handed back in `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let mut foo: ErrorOption<i32, &str> = Empty;
+    /// *foo.replace_if_empty(42).unwrap() += 1;
+    /// assert_eq!(foo, Value(43));
+    ///
+    /// assert_eq!(foo.replace_if_empty(7), Err(7));
+    /// assert_eq!(foo, Value(43));
+    ///
+    /// let mut bar: ErrorOption<i32, &str> = Error("error");
+    /// assert_eq!(bar.replace_if_empty(7), Err(7));
+    /// assert_eq!(bar, Error("error"));
+    /// ```
+    #[inline]
+    pub fn replace_if_empty(&mut self, value: T) -> Result<&mut T, T> {
+        match self {
+            Empty => Ok(self.insert(value)),
+            _ => Err(value)
+        }
+    }
 
     #[inline]
     pub fn get_or_insert(&mut self, value: T) -> &mut T {
@@ -898,9 +2167,112 @@ impl<T, E> ErrorOption<T, E> {
         mem::replace(self, Value(value))
     }
 
-    // ToDo: add zip_to_error and with error (need to figure out what to do with conflicting errors)
-    // Does it make sense to prioratize the first error and only porpagate the 2nd if the first has a value?
-    // Should we only ever propagate the first? Should this panic and have a function where a defaut error is provided?
+    /// Replaces the `ErrorOption` with the result of `f` applied to the current state,
+    /// returning the old value.
+    ///
+    /// Unlike [`replace`](ErrorOption::replace) the new state may be any variant and may
+    /// depend on the current one. `f` only borrows the current state, so no `Clone` is
+    /// needed to hand back the old value. If `f` panics `self` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let promote = |x: &ErrorOption<i32, &'static str>| match *x {
+    ///     Empty => Error("missing"),
+    ///     Value(value) => Value(value + 1),
+    ///     Error(error) => Error(error)
+    /// };
+    ///
+    /// let mut foo: ErrorOption<i32, &str> = Empty;
+    /// assert_eq!(foo.replace_with(promote), Empty);
+    /// assert_eq!(foo, Error("missing"));
+    ///
+    /// let mut bar: ErrorOption<i32, &str> = Value(42);
+    /// assert_eq!(bar.replace_with(promote), Value(42));
+    /// assert_eq!(bar, Value(43));
+    /// ```
+    #[inline]
+    pub fn replace_with<F>(&mut self, f: F) -> Self
+    where
+        F: FnOnce(&Self) -> Self
+    {
+        let new = f(self);
+        mem::replace(self, new)
+    }
+
+    /// Advances to the next state, dropping any contained value.
+    ///
+    /// The cycle order is `Empty -> Value(default) -> Error(default) -> Empty`,
+    /// so calling this three times returns to the starting variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<i32, String> = Empty;
+    /// assert_eq!(foo.clone().cycle(), Value(0));
+    /// assert_eq!(foo.clone().cycle().cycle(), Error(String::new()));
+    /// assert_eq!(foo.clone().cycle().cycle().cycle(), foo);
+    /// ```
+    #[inline]
+    pub fn cycle(self) -> Self
+    where
+        T: Default,
+        E: Default
+    {
+        match self {
+            Empty => Value(T::default()),
+            Value(_) => Error(E::default()),
+            Error(_) => Empty
+        }
+    }
+
+    /// Zips `self` with another `ErrorOption`, propagating errors.
+    ///
+    /// If both are `Value`, returns `Value((a, b))`. Conflicting errors are resolved
+    /// with *second error priority*: the second operand's error is returned if `self`
+    /// is a `Value`, otherwise the first error. An `Error` still wins over `Empty` on either side.
+    ///
+    /// | `self`     | `other`    | result         |
+    /// |------------|------------|----------------|
+    /// | `Value(a)` | `Value(b)` | `Value((a, b))`|
+    /// | `Value(a)` | `Empty`    | `Empty`        |
+    /// | `Value(a)` | `Error(y)` | `Error(y)`     |
+    /// | `Empty`    | `Value(b)` | `Empty`        |
+    /// | `Empty`    | `Empty`    | `Empty`        |
+    /// | `Empty`    | `Error(y)` | `Error(y)`     |
+    /// | `Error(x)` | `Value(b)` | `Error(x)`     |
+    /// | `Error(x)` | `Empty`    | `Error(x)`     |
+    /// | `Error(x)` | `Error(y)` | `Error(x)`     |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let value: ErrorOption<i32, &str> = Value(1);
+    /// let empty: ErrorOption<i32, &str> = Empty;
+    /// let x: ErrorOption<i32, &str> = Error("x");
+    /// let y: ErrorOption<char, &str> = Error("y");
+    ///
+    /// assert_eq!(value.zip_to_error(Value('b')), Value((1, 'b')));
+    /// assert_eq!(value.zip_to_error(Empty::<char, &str>), Empty);
+    /// assert_eq!(value.zip_to_error(y), Error("y"));
+    /// assert_eq!(empty.zip_to_error(Value('b')), Empty);
+    /// assert_eq!(empty.zip_to_error(Empty::<char, &str>), Empty);
+    /// assert_eq!(empty.zip_to_error(y), Error("y"));
+    /// assert_eq!(x.zip_to_error(Value('b')), Error("x"));
+    /// assert_eq!(x.zip_to_error(Empty::<char, &str>), Error("x"));
+    /// assert_eq!(x.zip_to_error(y), Error("x"));
+    /// ```
+    #[inline]
+    pub fn zip_to_error<U>(self, other: ErrorOption<U, E>) -> ErrorOption<(T, U), E> {
+        match (self, other) {
+            (Value(a), Value(b)) => Value((a, b)),
+            (Error(error), _) | (_, Error(error)) => Error(error),
+            _ => Empty
+        }
+    }
 
     #[inline]
     pub fn zip<U>(self, other: ErrorOption<U, E>) -> ErrorOption<(T, U), E> {
@@ -919,6 +2291,54 @@ impl<T, E> ErrorOption<T, E> {
         }
     }
 
+    /// Zips `self` with another `ErrorOption`, keeping whichever values are present.
+    ///
+    /// Unlike [`zip`](ErrorOption::zip) a single `Empty` does not discard the other value,
+    /// the result is `Empty` only if both are `Empty`. An `Error` wins over everything,
+    /// if both are `Error` the one of `self` is returned.
+    ///
+    /// | `self`     | `other`    | result                     |
+    /// |------------|------------|----------------------------|
+    /// | `Value(a)` | `Value(b)` | `Value((Some(a), Some(b)))`|
+    /// | `Value(a)` | `Empty`    | `Value((Some(a), None))`   |
+    /// | `Value(a)` | `Error(y)` | `Error(y)`                 |
+    /// | `Empty`    | `Value(b)` | `Value((None, Some(b)))`   |
+    /// | `Empty`    | `Empty`    | `Empty`                    |
+    /// | `Empty`    | `Error(y)` | `Error(y)`                 |
+    /// | `Error(x)` | `Value(b)` | `Error(x)`                 |
+    /// | `Error(x)` | `Empty`    | `Error(x)`                 |
+    /// | `Error(x)` | `Error(y)` | `Error(x)`                 |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let value: ErrorOption<i32, &str> = Value(1);
+    /// let empty: ErrorOption<i32, &str> = Empty;
+    /// let x: ErrorOption<i32, &str> = Error("x");
+    /// let y: ErrorOption<char, &str> = Error("y");
+    ///
+    /// assert_eq!(value.zip_options(Value('b')), Value((Some(1), Some('b'))));
+    /// assert_eq!(value.zip_options(Empty::<char, &str>), Value((Some(1), None)));
+    /// assert_eq!(value.zip_options(y), Error("y"));
+    /// assert_eq!(empty.zip_options(Value('b')), Value((None, Some('b'))));
+    /// assert_eq!(empty.zip_options(Empty::<char, &str>), Empty);
+    /// assert_eq!(empty.zip_options(y), Error("y"));
+    /// assert_eq!(x.zip_options(Value('b')), Error("x"));
+    /// assert_eq!(x.zip_options(Empty::<char, &str>), Error("x"));
+    /// assert_eq!(x.zip_options(y), Error("x"));
+    /// ```
+    #[inline]
+    pub fn zip_options<U>(self, other: ErrorOption<U, E>) -> ErrorOption<(Option<T>, Option<U>), E> {
+        match (self, other) {
+            (Error(error), _) | (_, Error(error)) => Error(error),
+            (Value(a), Value(b)) => Value((Some(a), Some(b))),
+            (Value(a), Empty) => Value((Some(a), None)),
+            (Empty, Value(b)) => Value((None, Some(b))),
+            (Empty, Empty) => Empty
+        }
+    }
+
     #[inline]
     pub fn zip_with_option<U>(self, other: Option<U>) -> Option<(T, U)> {
         match (self, other) {
@@ -951,16 +2371,125 @@ impl<T, E> ErrorOption<T, E> {
     }
 
 
-    // ToDo: 
+    /// Folds every `Value` of `iter` into an accumulator, skipping `Empty`s and stopping at the first `Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: Vec<ErrorOption<i32, &str>> = vec![Value(1), Empty, Value(2), Value(3)];
+    /// assert_eq!(ErrorOption::fold_values(foo, 0, |sum, x| sum + x), Value(6));
+    ///
+    /// let mut seen = Vec::new();
+    /// let bar: Vec<ErrorOption<i32, &str>> = vec![Value(1), Error("broken"), Value(3)];
+    /// let folded = ErrorOption::fold_values(bar, 0, |sum, x| {
+    ///     seen.push(x);
+    ///     sum + x
+    /// });
+    /// assert_eq!(folded, Error("broken"));
+    /// assert_eq!(seen, vec![1]);
+    /// ```
+    #[inline]
+    pub fn fold_values<I, B, F>(iter: I, init: B, mut f: F) -> ErrorOption<B, E>
+    where
+        I: IntoIterator<Item = ErrorOption<T, E>>,
+        F: FnMut(B, T) -> B
+    {
+        let mut accumulator = init;
+        for item in iter {
+            match item {
+                Value(value) => accumulator = f(accumulator, value),
+                Empty => {},
+                Error(error) => return Error(error)
+            }
+        }
+        Value(accumulator)
+    }
+
+    // ToDo:
     //
     // Iterators and Special Optimized traits
     //
     // General:
-    // Do something about nested ErrorOptions
     // Fix unwrap_failed to be 2 methods
     // Implement iterators
 }
 
+impl<T, E> ErrorOption<ErrorOption<T, E>, E> {
+    /// Converts from `ErrorOption<ErrorOption<T, E>, E>` to `ErrorOption<T, E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<ErrorOption<i32, &str>, &str> = Value(Value(42));
+    /// assert_eq!(foo.flatten(), Value(42));
+    ///
+    /// let bar: ErrorOption<ErrorOption<i32, &str>, &str> = Value(Error("inner"));
+    /// assert_eq!(bar.flatten(), Error("inner"));
+    ///
+    /// let baz: ErrorOption<ErrorOption<i32, &str>, &str> = Error("outer");
+    /// assert_eq!(baz.flatten(), Error("outer"));
+    ///
+    /// let qux: ErrorOption<ErrorOption<i32, &str>, &str> = Value(Empty);
+    /// assert_eq!(qux.flatten(), Empty);
+    /// ```
+    #[inline]
+    pub fn flatten(self) -> ErrorOption<T, E> {
+        match self {
+            Value(inner) => inner,
+            Empty => Empty,
+            Error(error) => Error(error)
+        }
+    }
+}
+
+impl<T, E> ErrorOption<ErrorOption<ErrorOption<T, E>, E>, E> {
+    /// Converts from `ErrorOption<ErrorOption<ErrorOption<T, E>, E>, E>` to `ErrorOption<T, E>`.
+    ///
+    /// The outermost `Error` or `Empty` wins, otherwise the next level is inspected,
+    /// so this is the same as calling [`flatten`] twice.
+    ///
+    /// [`flatten`]: ErrorOption::flatten
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+    /// let foo: ErrorOption<ErrorOption<ErrorOption<i32, &str>, &str>, &str> = Value(Value(Value(42)));
+    /// assert_eq!(foo.flatten3(), Value(42));
+    ///
+    /// let bar: ErrorOption<ErrorOption<ErrorOption<i32, &str>, &str>, &str> = Value(Value(Error("inner")));
+    /// assert_eq!(bar.flatten3(), Error("inner"));
+    ///
+    /// let baz: ErrorOption<ErrorOption<ErrorOption<i32, &str>, &str>, &str> = Value(Error("middle"));
+    /// assert_eq!(baz.flatten3(), Error("middle"));
+    ///
+    /// let qux: ErrorOption<ErrorOption<ErrorOption<i32, &str>, &str>, &str> = Value(Empty);
+    /// assert_eq!(qux.flatten3(), Empty);
+    ///
+    /// let foo: ErrorOption<ErrorOption<ErrorOption<i32, &str>, &str>, &str> = Error("outer");
+    /// assert_eq!(foo.flatten3(), Error("outer"));
+    /// ```
+    #[inline]
+    pub fn flatten3(self) -> ErrorOption<T, E> {
+        match self {
+            Value(Value(inner)) => inner,
+            Value(Empty) | Empty => Empty,
+            Value(Error(error)) | Error(error) => Error(error)
+        }
+    }
+}
+
+/// The non-`Value` arms of an [`ErrorOption`], passed to [`ErrorOption::or_else_full`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ErrorOptionFailure<E> {
+    /// The `ErrorOption` was `Empty`.
+    Empty,
+    /// The `ErrorOption` was an `Error`.
+    Error(E)
+}
+
 impl<T, U ,E> ErrorOption<(T, U), E> {
     /// Converts an `ErrorOption<(T, U), E>` to `(ErrorOption<T, E>, ErrorOption<U, E>)`
     /// 
@@ -1023,6 +2552,27 @@ impl<T, E> Default for ErrorOption<T, E> {
     }
 }
 
+/// Clones the contained value or error.
+///
+/// Like a derived implementation this only requires `T: Clone` and `E: Clone`, use
+/// [`ErrorOption::clone_with`] if the error can not be cloned.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use urt::erroroption::ErrorOption::{self, Value, Error};
+/// let foo: ErrorOption<Vec<u8>, Arc<str>> = Error(Arc::from("shared"));
+/// let bar = foo.clone();
+/// assert_eq!(bar, foo);
+///
+/// // Both share the same allocation.
+/// let (Error(foo), Error(bar)) = (foo, bar) else { unreachable!() };
+/// assert!(Arc::ptr_eq(&foo, &bar));
+///
+/// let baz: ErrorOption<Vec<u8>, Arc<str>> = Value(vec![1, 2, 3]);
+/// assert_eq!(baz.clone(), Value(vec![1, 2, 3]));
+/// ```
 impl<T: Clone, E: Clone> Clone for ErrorOption<T, E> {
     fn clone(&self) -> Self {
         match self {
@@ -1041,7 +2591,258 @@ impl<T: Clone, E: Clone> Clone for ErrorOption<T, E> {
     }
 }
 
+/// Appends every `Value` to the contained [`Vec`], skipping `Empty`s and stopping at the first `Error`.
+///
+/// An `Empty` slot starts out as an empty [`Vec`], an `Error` slot is left untouched.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+/// let mut foo: ErrorOption<Vec<i32>, &str> = Value(vec![1]);
+/// foo.extend([Value(2), Empty, Value(3)]);
+/// assert_eq!(foo, Value(vec![1, 2, 3]));
+///
+/// let mut bar: ErrorOption<Vec<i32>, &str> = Empty;
+/// bar.extend([Value(1), Error("broken"), Value(3)]);
+/// assert_eq!(bar, Error("broken"));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T, E> Extend<ErrorOption<T, E>> for ErrorOption<Vec<T>, E> {
+    fn extend<I: IntoIterator<Item = ErrorOption<T, E>>>(&mut self, iter: I) {
+        let values = match self {
+            Value(values) => values,
+            Empty => self.insert(Vec::new()),
+            Error(_) => return
+        };
+
+        for item in iter {
+            match item {
+                Value(value) => values.push(value),
+                Empty => {},
+                Error(error) => {
+                    *self = Error(error);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Collects a batch of [`ErrorOption`]s one item at a time.
+///
+/// Every `Value` is appended to the collection `C` until the first `Error` is pushed,
+/// which is kept while later values are dropped. `Empty`s are counted.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::{ErrorOptionAccumulator, ErrorOption::{self, Value, Empty, Error}};
+/// let mut foo: ErrorOptionAccumulator<Vec<char>, &str> = ErrorOptionAccumulator::new();
+/// let items: [ErrorOption<char, &str>; 3] = [Value('a'), Empty, Value('b')];
+/// for item in items {
+///     foo.push(item);
+/// }
+/// assert_eq!(foo.empties(), 1);
+/// assert_eq!(foo.finish(), Value(vec!['a', 'b']));
+///
+/// let mut bar: ErrorOptionAccumulator<Vec<char>, &str> = ErrorOptionAccumulator::new();
+/// let items: [ErrorOption<char, &str>; 5] = [Value('a'), Error("first"), Empty, Error("second"), Value('b')];
+/// for item in items {
+///     bar.push(item);
+/// }
+/// assert_eq!(bar.empties(), 1);
+/// assert!(bar.is_error());
+/// assert_eq!(bar.finish(), Error("first"));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ErrorOptionAccumulator<C, E> {
+    values: C,
+    error: Option<E>,
+    empties: usize
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Default, E> ErrorOptionAccumulator<C, E> {
+    /// Creates an accumulator with an empty collection.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_collection(C::default())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C, E> ErrorOptionAccumulator<C, E> {
+    /// Creates an accumulator appending to `values`.
+    #[inline]
+    pub fn with_collection(values: C) -> Self {
+        Self { values, error: None, empties: 0 }
+    }
+
+    /// Pushes a single item, see the type level documentation for the policy.
+    #[inline]
+    pub fn push<T>(&mut self, item: ErrorOption<T, E>)
+    where
+        C: Extend<T>
+    {
+        match item {
+            Value(value) if self.error.is_none() => self.values.extend(Some(value)),
+            Value(_) => {},
+            Empty => self.empties += 1,
+            Error(error) => {
+                if self.error.is_none() {
+                    self.error = Some(error)
+                }
+            }
+        }
+    }
+
+    /// Returns the number of `Empty`s pushed so far.
+    #[inline]
+    pub fn empties(&self) -> usize {
+        self.empties
+    }
+
+    /// Returns `true` if an `Error` has been pushed.
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Returns the first pushed `Error`, or else the collected values.
+    #[inline]
+    pub fn finish(self) -> ErrorOption<C, E> {
+        match self.error {
+            Some(error) => Error(error),
+            None => Value(self.values)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Default, E> Default for ErrorOptionAccumulator<C, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Consumes an iterator of [`ErrorOption`]s, separating the values and the errors.
+///
+/// `Empty`s are dropped. Both [`Vec`]s keep the order in which the items were yielded.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::{drain_errors, ErrorOption::{self, Value, Empty, Error}};
+/// let batch: Vec<ErrorOption<i32, &str>> = vec![Value(1), Error("a"), Empty, Value(2), Empty, Error("b")];
+///
+/// let (values, errors) = drain_errors(batch);
+/// assert_eq!(values, [1, 2]);
+/// assert_eq!(errors, ["a", "b"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn drain_errors<I, T, E>(iter: I) -> (Vec<T>, Vec<E>)
+where
+    I: IntoIterator<Item = ErrorOption<T, E>>
+{
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+
+    for item in iter {
+        match item {
+            Value(value) => values.push(value),
+            Empty => {},
+            Error(error) => errors.push(error)
+        }
+    }
+
+    (values, errors)
+}
+
+/// Wraps an iterator of [`ErrorOption`]s, skipping `Empty`s and yielding the rest as [`Result`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::{values, ErrorOption::{self, Value, Empty, Error}};
+/// let stream: Vec<ErrorOption<i32, &str>> = vec![Empty, Value(1), Error("a"), Empty, Empty, Value(2)];
+///
+/// let mut handled = Vec::new();
+/// for item in values(stream.into_iter()) {
+///     match item {
+///         Ok(value) => handled.push(format!("value {value}")),
+///         Err(error) => handled.push(format!("error {error}"))
+///     }
+/// }
+/// assert_eq!(handled, ["value 1", "error a", "value 2"]);
+/// ```
+pub fn values<I>(iter: I) -> Values<I> {
+    Values { iter }
+}
+
+/// An iterator over the values and errors of an iterator of [`ErrorOption`]s, skipping `Empty`s.
+///
+/// This `struct` is created by the [`values`] function.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::{values, ErrorOption::{self, Value, Empty, Error}};
+/// let stream: [ErrorOption<i32, &str>; 4] = [Value(1), Empty, Error("a"), Empty];
+///
+/// let mut iter = values(stream.into_iter());
+/// assert_eq!(iter.size_hint(), (0, Some(4)));
+/// assert_eq!(iter.next_back(), Some(Err("a")));
+/// assert_eq!(iter.next(), Some(Ok(1)));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Values<I> {
+    iter: I
+}
+
+impl<T, E, I> Iterator for Values<I>
+where
+    I: Iterator<Item = ErrorOption<T, E>>
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(|item| match item {
+            Value(value) => Some(Ok(value)),
+            Empty => None,
+            Error(error) => Some(Err(error))
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, E, I> DoubleEndedIterator for Values<I>
+where
+    I: DoubleEndedIterator<Item = ErrorOption<T, E>>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.by_ref().rev().find_map(|item| match item {
+            Value(value) => Some(Ok(value)),
+            Empty => None,
+            Error(error) => Some(Err(error))
+        })
+    }
+}
 
+impl<T, E, I> FusedIterator for Values<I>
+where
+    I: FusedIterator<Item = ErrorOption<T, E>>
+{}
 
 impl<T, E> IntoIterator for ErrorOption<T, E> {
     type Item = T;
@@ -1075,6 +2876,12 @@ impl<'a, T, E> IntoIterator for &'a mut ErrorOption<T, E> {
 // The ErrorOption Iterators
 /////////////////////////////////////////////////////////////////////////////
 
+// Both ends of every iterator share the single `inner` slot, `next` and `next_back`
+// take from it, so the element can never be yielded twice.
+
+/// An iterator over a reference to the `Value` of an [`ErrorOption`].
+///
+/// This `struct` is created by the [`ErrorOption::iter`] function.
 #[derive(Debug)]
 pub struct Iter<'a, T: 'a> {
     inner: Option<&'a T>
@@ -1113,6 +2920,9 @@ impl<T> Clone for Iter<'_, T> {
     }
 }
 
+/// An iterator over a mutable reference to the `Value` of an [`ErrorOption`].
+///
+/// This `struct` is created by the [`ErrorOption::iter_mut`] function.
 #[derive(Debug)]
 pub struct IterMut<'a, T: 'a> {
     inner: Option<&'a mut T>,
@@ -1143,6 +2953,23 @@ impl<T> ExactSizeIterator for IterMut<'_, T> {}
 
 impl<T> FusedIterator for IterMut<'_, T> {}
 
+/// An iterator over the `Value` of an [`ErrorOption`].
+///
+/// This `struct` is created by the `into_iter` method on [`ErrorOption`].
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::ErrorOption::{self, Value};
+/// let foo: ErrorOption<i32, &str> = Value(42);
+/// assert_eq!(foo.into_iter().rev().collect::<Vec<_>>(), vec![42]);
+///
+/// let mut iter = foo.into_iter();
+/// assert_eq!(iter.len(), 1);
+/// assert_eq!(iter.next(), Some(42));
+/// assert_eq!(iter.len(), 0);
+/// assert_eq!(iter.next_back(), None);
+/// ```
 #[derive(Clone, Debug)]
 pub struct IntoIter<T> {
     inner: Option<T>
@@ -0,0 +1,297 @@
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    mem::ManuallyDrop
+};
+
+use crate::erroroption::ErrorOption::{self, Value, Empty, Error};
+
+/// The known values of the tag byte of an [`ErrorOptionRepr`], telling which field of
+/// the payload is active.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::ffi::{ErrorOptionTag, UnknownTag};
+/// assert_eq!(ErrorOptionTag::try_from(0), Ok(ErrorOptionTag::Value));
+/// assert_eq!(ErrorOptionTag::try_from(2), Ok(ErrorOptionTag::Error));
+/// assert_eq!(ErrorOptionTag::try_from(7), Err(UnknownTag(7)));
+/// assert_eq!(u8::from(ErrorOptionTag::Empty), 1);
+/// ```
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ErrorOptionTag {
+    Value = 0,
+    Empty = 1,
+    Error = 2
+}
+
+impl TryFrom<u8> for ErrorOptionTag {
+    type Error = UnknownTag;
+
+    #[inline]
+    fn try_from(tag: u8) -> Result<Self, UnknownTag> {
+        match tag {
+            0 => Ok(ErrorOptionTag::Value),
+            1 => Ok(ErrorOptionTag::Empty),
+            2 => Ok(ErrorOptionTag::Error),
+            tag => Err(UnknownTag(tag))
+        }
+    }
+}
+
+impl From<ErrorOptionTag> for u8 {
+    #[inline]
+    fn from(tag: ErrorOptionTag) -> Self {
+        tag as u8
+    }
+}
+
+/// Error returned when the tag byte of an [`ErrorOptionRepr`] is not one of the
+/// values of [`ErrorOptionTag`], holding the offending byte.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::ffi::UnknownTag;
+/// assert_eq!(UnknownTag(7).to_string(), "unknown ErrorOption tag 7");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UnknownTag(pub u8);
+
+impl Display for UnknownTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown ErrorOption tag {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownTag {}
+
+/// The payload of an [`ErrorOptionRepr`], only the field named by the tag is initialized.
+#[repr(C)]
+pub union ErrorOptionPayload<T, E> {
+    pub value: ManuallyDrop<T>,
+    pub empty: (),
+    pub error: ManuallyDrop<E>
+}
+
+/// `#[repr(C)]` representation of an [`ErrorOption`] with a stable layout for passing
+/// across an FFI boundary.
+///
+/// The layout is a `u8` tag followed by a union of the payloads, the C equivalent is
+///
+/// ```c
+/// struct ErrorOptionRepr {
+///     uint8_t tag; /* 0 = Value, 1 = Empty, 2 = Error */
+///     union {
+///         T value;
+///         E error;
+///     } payload;
+/// };
+/// ```
+///
+/// The layout of `ErrorOption` itself is unspecified, convert with [`From`] and
+/// [`TryFrom`] when crossing the boundary. Since the tag may have been written by foreign
+/// code it is stored as a raw byte and checked on every read, a tag outside of
+/// [`ErrorOptionTag`] is reported as [`UnknownTag`].
+///
+/// Whoever writes an `ErrorOptionRepr` must initialize the payload field named by the
+/// tag. Dropping an `ErrorOptionRepr` drops that payload, a payload behind an unknown
+/// tag is leaked.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::ErrorOption::{self, Value, Empty, Error};
+/// # use urt::ffi::{ErrorOptionRepr, ErrorOptionTag};
+/// let foo: ErrorOptionRepr<u32, i8> = Value(42).into();
+/// assert_eq!(foo.tag(), Ok(ErrorOptionTag::Value));
+/// assert_eq!(ErrorOption::try_from(foo), Ok(Value(42)));
+///
+/// let bar: ErrorOptionRepr<u32, i8> = Empty.into();
+/// assert_eq!(bar.tag(), Ok(ErrorOptionTag::Empty));
+/// assert_eq!(ErrorOption::try_from(bar), Ok(Empty));
+///
+/// let baz: ErrorOptionRepr<u32, i8> = Error(-1).into();
+/// assert_eq!(baz.tag(), Ok(ErrorOptionTag::Error));
+/// assert_eq!(ErrorOption::try_from(baz), Ok(Error(-1)));
+/// ```
+///
+/// Payloads with drop glue round-trip without being dropped twice:
+///
+/// ```
+/// # use urt::erroroption::ErrorOption::{self, Value, Error};
+/// # use urt::ffi::ErrorOptionRepr;
+/// let foo: ErrorOptionRepr<String, String> = Value(String::from("value")).into();
+/// assert_eq!(ErrorOption::try_from(foo), Ok(Value(String::from("value"))));
+///
+/// let bar: ErrorOptionRepr<String, String> = Error(String::from("error")).into();
+/// assert_eq!(ErrorOption::try_from(bar), Ok(Error(String::from("error"))));
+///
+/// // Dropping the repr drops the payload.
+/// let baz: ErrorOptionRepr<String, String> = Value(String::from("dropped")).into();
+/// drop(baz);
+/// ```
+///
+/// A tag written by foreign code which is not known is rejected. The tag is the first
+/// field of a `#[repr(C)]` struct, so it sits at offset 0:
+///
+/// ```
+/// # use urt::erroroption::ErrorOption::{self, Empty};
+/// # use urt::ffi::{ErrorOptionRepr, UnknownTag};
+/// let mut foo: ErrorOptionRepr<u32, i8> = Empty.into();
+/// // SAFETY: the tag is a plain `u8` at offset 0 and `Empty` has no payload to drop.
+/// unsafe { *(&mut foo as *mut ErrorOptionRepr<u32, i8>).cast::<u8>() = 7 };
+///
+/// assert_eq!(foo.tag(), Err(UnknownTag(7)));
+/// assert_eq!(foo.as_error_option(), Err(UnknownTag(7)));
+/// assert_eq!(ErrorOption::try_from(foo), Err(UnknownTag(7)));
+/// ```
+#[repr(C)]
+pub struct ErrorOptionRepr<T, E> {
+    tag: u8,
+    payload: ErrorOptionPayload<T, E>
+}
+
+impl<T, E> ErrorOptionRepr<T, E> {
+    /// Returns the tag naming the active payload, or [`UnknownTag`] if the raw tag byte
+    /// is not a known tag.
+    #[inline]
+    pub fn tag(&self) -> Result<ErrorOptionTag, UnknownTag> {
+        ErrorOptionTag::try_from(self.tag)
+    }
+
+    /// Returns a reference to the payload.
+    #[inline]
+    pub const fn payload(&self) -> &ErrorOptionPayload<T, E> {
+        &self.payload
+    }
+
+    /// Converts from `&ErrorOptionRepr<T, E>` to `ErrorOption<&T, &E>`, or returns
+    /// [`UnknownTag`] if the raw tag byte is not a known tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use urt::erroroption::ErrorOption::{Value, Empty, Error};
+    /// # use urt::ffi::ErrorOptionRepr;
+    /// let foo: ErrorOptionRepr<u32, i8> = Value(42).into();
+    /// assert_eq!(foo.as_error_option(), Ok(Value(&42)));
+    ///
+    /// let bar: ErrorOptionRepr<u32, i8> = Empty.into();
+    /// assert_eq!(bar.as_error_option(), Ok(Empty));
+    ///
+    /// let baz: ErrorOptionRepr<u32, i8> = Error(-1).into();
+    /// assert_eq!(baz.as_error_option(), Ok(Error(&-1)));
+    /// ```
+    #[inline]
+    pub fn as_error_option(&self) -> Result<ErrorOption<&T, &E>, UnknownTag> {
+        let tag = self.tag()?;
+        // SAFETY: the payload field named by a known tag is initialized, which is the
+        // invariant every writer of an `ErrorOptionRepr` has to uphold.
+        unsafe {
+            Ok(match tag {
+                ErrorOptionTag::Value => Value(&self.payload.value),
+                ErrorOptionTag::Empty => Empty,
+                ErrorOptionTag::Error => Error(&self.payload.error)
+            })
+        }
+    }
+}
+
+impl<T, E> From<ErrorOption<T, E>> for ErrorOptionRepr<T, E> {
+    #[inline]
+    fn from(error_option: ErrorOption<T, E>) -> Self {
+        match error_option {
+            Value(value) => ErrorOptionRepr {
+                tag: ErrorOptionTag::Value as u8,
+                payload: ErrorOptionPayload { value: ManuallyDrop::new(value) }
+            },
+            Empty => ErrorOptionRepr {
+                tag: ErrorOptionTag::Empty as u8,
+                payload: ErrorOptionPayload { empty: () }
+            },
+            Error(error) => ErrorOptionRepr {
+                tag: ErrorOptionTag::Error as u8,
+                payload: ErrorOptionPayload { error: ManuallyDrop::new(error) }
+            }
+        }
+    }
+}
+
+/// Converts an [`ErrorOptionRepr`] back into an [`ErrorOption`].
+///
+/// A repr with an unknown tag is rejected with [`UnknownTag`], its payload is leaked
+/// since it is unknown which field, if any, is initialized.
+impl<T, E> TryFrom<ErrorOptionRepr<T, E>> for ErrorOption<T, E> {
+    type Error = UnknownTag;
+
+    #[inline]
+    fn try_from(repr: ErrorOptionRepr<T, E>) -> Result<Self, UnknownTag> {
+        // The payload is moved out below, so the `Drop` of the repr must not run.
+        let mut repr = ManuallyDrop::new(repr);
+        let tag = repr.tag()?;
+        // SAFETY: the payload field named by a known tag is initialized, and `repr`
+        // is never used again after the payload is taken.
+        unsafe {
+            Ok(match tag {
+                ErrorOptionTag::Value => Value(ManuallyDrop::take(&mut repr.payload.value)),
+                ErrorOptionTag::Empty => Empty,
+                ErrorOptionTag::Error => Error(ManuallyDrop::take(&mut repr.payload.error))
+            })
+        }
+    }
+}
+
+impl<T, E> Drop for ErrorOptionRepr<T, E> {
+    fn drop(&mut self) {
+        // SAFETY: the payload field named by a known tag is initialized. Behind an
+        // unknown tag nothing is known to be initialized, so nothing is dropped.
+        unsafe {
+            match self.tag() {
+                Ok(ErrorOptionTag::Value) => ManuallyDrop::drop(&mut self.payload.value),
+                Ok(ErrorOptionTag::Error) => ManuallyDrop::drop(&mut self.payload.error),
+                Ok(ErrorOptionTag::Empty) | Err(_) => {}
+            }
+        }
+    }
+}
+
+/// Compares the tags and, for known tags, the active payloads.
+///
+/// # Examples
+///
+/// ```
+/// # use urt::erroroption::ErrorOption::{Value, Error};
+/// # use urt::ffi::ErrorOptionRepr;
+/// let foo: ErrorOptionRepr<u32, u32> = Value(42).into();
+/// let bar: ErrorOptionRepr<u32, u32> = Value(42).into();
+/// let baz: ErrorOptionRepr<u32, u32> = Error(42).into();
+/// assert_eq!(foo, bar);
+/// assert_ne!(foo, baz);
+/// ```
+impl<T: PartialEq, E: PartialEq> PartialEq for ErrorOptionRepr<T, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_error_option() == other.as_error_option()
+    }
+}
+
+impl<T: Eq, E: Eq> Eq for ErrorOptionRepr<T, E> {}
+
+impl<T: Hash, E: Hash> Hash for ErrorOptionRepr<T, E> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_error_option().hash(state);
+    }
+}
+
+impl<T: Debug, E: Debug> Debug for ErrorOptionRepr<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorOptionRepr")
+            .field("tag", &self.tag)
+            .field("payload", &self.as_error_option())
+            .finish()
+    }
+}
@@ -1,10 +1,13 @@
 //! Urt (unambiguous result types) extends the standart libraries [`Option`] and [`Result`] types by adding 
 //! multiple additional types which may be useful to return. 
 
-#![cfg_attr(not(feature = "str"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(rustdoc::broken_intra_doc_links)]
 #![doc(test(attr(deny(warnings))))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Adds the `ErrorOption` enum which acts like Result and Option in one with 
 /// variants `Value`, `Empty` and `Error` 
 pub mod erroroption;
@@ -13,3 +16,6 @@ pub mod doubleoption;
 /// Adds the `Double` enum for unopinionated [`Result`]s.
 pub mod double;
 
+/// Adds `#[repr(C)]` types for passing the enums of this crate across an FFI boundary.
+#[cfg(feature = "ffi")]
+pub mod ffi;